@@ -0,0 +1,111 @@
+use core::time::Duration;
+use std::time::Instant;
+
+use dyn_clone::{clone_trait_object, DynClone};
+
+use crate::{
+    error::{RecvTimeoutError, TryRecvError},
+    introspect::Introspect,
+};
+
+//
+pub trait BlockingReceiver<T>: Introspect {
+    fn recv(&mut self) -> Option<T>;
+
+    fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError>;
+
+    fn recv_deadline(&mut self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+        self.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+    }
+
+    fn try_recv(&mut self) -> Result<T, TryRecvError>;
+
+    fn close(&mut self) -> bool {
+        false
+    }
+}
+
+pub trait CloneableBlockingReceiver<T>: DynClone + Introspect {
+    fn recv(&mut self) -> Option<T>;
+
+    fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError>;
+
+    fn recv_deadline(&mut self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+        self.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+    }
+
+    fn try_recv(&mut self) -> Result<T, TryRecvError>;
+
+    fn close(&mut self) -> bool {
+        false
+    }
+}
+clone_trait_object!(<T> CloneableBlockingReceiver<T>);
+
+//
+#[cfg(any(feature = "impl_tokio", feature = "impl_async_channel"))]
+pub(crate) mod block_on {
+    use core::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+    use std::{
+        sync::Arc,
+        task::Wake,
+        thread::{self, Thread},
+        time::Instant,
+    };
+
+    use crate::error::RecvTimeoutError;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    // Returns `None` if the deadline elapsed before the future completed.
+    pub(crate) fn block_on_deadline<F: Future>(
+        fut: F,
+        deadline: Option<Instant>,
+    ) -> Option<F::Output> {
+        let mut fut = pin!(fut);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+
+        loop {
+            if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+                return Some(output);
+            }
+
+            match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return None;
+                    }
+                    thread::park_timeout(deadline - now);
+                }
+                None => thread::park(),
+            }
+        }
+    }
+
+    pub(crate) fn recv_deadline<T, F: Future<Output = Option<T>>>(
+        fut: F,
+        deadline: Option<Instant>,
+    ) -> Result<T, RecvTimeoutError> {
+        match block_on_deadline(fut, deadline) {
+            Some(Some(v)) => Ok(v),
+            Some(None) => Err(RecvTimeoutError::Closed),
+            None => Err(RecvTimeoutError::Timeout),
+        }
+    }
+}
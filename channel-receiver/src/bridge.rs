@@ -0,0 +1,46 @@
+pub use flume::{Receiver as BridgeReceiver, Sender as BridgeSender};
+
+//
+// For handing one end of a channel to a std thread and the other to async code. flume handles work
+// from both sides, so the receiver is an `AsyncReceiver` of either flavour and a
+// `BlockingReceiver` at once. `channel_sender::bridge` re-exports these.
+pub fn channel<T>(capacity: usize) -> (BridgeSender<T>, BridgeReceiver<T>) {
+    flume::bounded(capacity)
+}
+
+pub fn unbounded_channel<T>() -> (BridgeSender<T>, BridgeReceiver<T>) {
+    flume::unbounded()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{blocking::BlockingReceiver, multi_consumer::AsyncReceiver};
+
+    #[tokio::test]
+    async fn test_blocking_to_async() {
+        let (tx, rx) = channel::<usize>(1);
+        let mut receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+        let handle = std::thread::spawn(move || {
+            assert_eq!(tx.send(1), Ok(()));
+            assert_eq!(tx.send(2), Ok(()));
+        });
+        assert_eq!(receiver.recv().await, Some(1));
+        assert_eq!(receiver.recv().await, Some(2));
+        handle.join().unwrap();
+        assert_eq!(receiver.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_async_to_blocking() {
+        let (tx, rx) = unbounded_channel::<usize>();
+        let mut receiver: Box<dyn BlockingReceiver<usize> + Send> = Box::new(rx);
+        let handle =
+            std::thread::spawn(move || (receiver.recv(), receiver.recv(), receiver.recv()));
+        assert_eq!(tx.send_async(1).await, Ok(()));
+        assert_eq!(tx.send_async(2).await, Ok(()));
+        drop(tx);
+        assert_eq!(handle.join().unwrap(), (Some(1), Some(2), None));
+    }
+}
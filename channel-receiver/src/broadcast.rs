@@ -0,0 +1,47 @@
+use dyn_clone::{clone_trait_object, DynClone};
+
+use crate::{
+    error::{RecvError, TryRecvError},
+    introspect::Introspect,
+};
+
+//
+// Every receiver sees every value. A receiver that falls more than the capacity behind skips the
+// oldest values and is told how many with `Lagged`, the next call picks up from the oldest one
+// still buffered.
+#[async_trait::async_trait]
+pub trait BroadcastReceiver<T>: Introspect {
+    async fn recv(&mut self) -> Result<T, RecvError>
+    where
+        T: Send;
+
+    fn try_recv(&mut self) -> Result<T, TryRecvError>;
+}
+
+#[async_trait::async_trait]
+impl<T, R> BroadcastReceiver<T> for Box<R>
+where
+    R: BroadcastReceiver<T> + Send + ?Sized,
+{
+    async fn recv(&mut self) -> Result<T, RecvError>
+    where
+        T: Send,
+    {
+        (**self).recv().await
+    }
+
+    fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        (**self).try_recv()
+    }
+}
+
+// A clone starts out where the original is, and from then on sees every value on its own.
+#[async_trait::async_trait]
+pub trait CloneableBroadcastReceiver<T>: DynClone + Introspect {
+    async fn recv(&mut self) -> Result<T, RecvError>
+    where
+        T: Send;
+
+    fn try_recv(&mut self) -> Result<T, TryRecvError>;
+}
+clone_trait_object!(<T> CloneableBroadcastReceiver<T>);
@@ -1,9 +1,12 @@
 //
 #[derive(Debug, Eq)]
+#[non_exhaustive]
 pub enum TryRecvError {
     Empty,
     Closed,
     Disconnected,
+    // Broadcast only, the receiver fell behind and this many values were skipped.
+    Lagged(u64),
 }
 impl core::fmt::Display for TryRecvError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -12,10 +15,91 @@ impl core::fmt::Display for TryRecvError {
 }
 impl std::error::Error for TryRecvError {}
 impl core::cmp::PartialEq for TryRecvError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Lagged(n1), Self::Lagged(n2)) => n1 == n2,
+            _ => matches!(
+                (self, other),
+                (Self::Empty, Self::Empty)
+                    | (Self::Closed, Self::Closed)
+                    | (Self::Closed, Self::Disconnected)
+                    | (Self::Disconnected, Self::Disconnected)
+                    | (Self::Disconnected, Self::Closed)
+            ),
+        }
+    }
+}
+
+impl TryRecvError {
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    pub fn is_closed_or_disconnected(&self) -> bool {
+        matches!(self, Self::Closed | Self::Disconnected)
+    }
+
+    pub fn is_lagged(&self) -> bool {
+        matches!(self, Self::Lagged(_))
+    }
+}
+
+//
+#[derive(Debug, Eq)]
+pub enum RecvError {
+    Closed,
+    Disconnected,
+    Lagged(u64),
+}
+impl core::fmt::Display for RecvError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+impl std::error::Error for RecvError {}
+impl core::cmp::PartialEq for RecvError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Lagged(n1), Self::Lagged(n2)) => n1 == n2,
+            _ => matches!(
+                (self, other),
+                (Self::Closed, Self::Closed)
+                    | (Self::Closed, Self::Disconnected)
+                    | (Self::Disconnected, Self::Disconnected)
+                    | (Self::Disconnected, Self::Closed)
+            ),
+        }
+    }
+}
+
+impl RecvError {
+    pub fn is_closed_or_disconnected(&self) -> bool {
+        matches!(self, Self::Closed | Self::Disconnected)
+    }
+
+    pub fn is_lagged(&self) -> bool {
+        matches!(self, Self::Lagged(_))
+    }
+}
+
+//
+#[derive(Debug, Eq)]
+pub enum RecvTimeoutError {
+    Timeout,
+    Closed,
+    Disconnected,
+}
+impl core::fmt::Display for RecvTimeoutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+impl std::error::Error for RecvTimeoutError {}
+impl core::cmp::PartialEq for RecvTimeoutError {
     fn eq(&self, other: &Self) -> bool {
         matches!(
             (self, other),
-            (Self::Empty, Self::Empty)
+            (Self::Timeout, Self::Timeout)
                 | (Self::Closed, Self::Closed)
                 | (Self::Closed, Self::Disconnected)
                 | (Self::Disconnected, Self::Disconnected)
@@ -24,9 +108,9 @@ impl core::cmp::PartialEq for TryRecvError {
     }
 }
 
-impl TryRecvError {
-    pub fn is_empty(&self) -> bool {
-        matches!(self, Self::Empty)
+impl RecvTimeoutError {
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout)
     }
 
     pub fn is_closed_or_disconnected(&self) -> bool {
@@ -58,5 +142,29 @@ mod tests {
         assert_eq!(TryRecvError::Disconnected, TryRecvError::Disconnected);
         assert_ne!(TryRecvError::Empty, TryRecvError::Closed);
         assert_ne!(TryRecvError::Empty, TryRecvError::Disconnected);
+        assert_eq!(TryRecvError::Lagged(1), TryRecvError::Lagged(1));
+        assert_ne!(TryRecvError::Lagged(1), TryRecvError::Lagged(2));
+        assert_ne!(TryRecvError::Lagged(1), TryRecvError::Empty);
+    }
+
+    #[test]
+    fn test_recv_error_partial_eq() {
+        assert_eq!(RecvError::Closed, RecvError::Disconnected);
+        assert_eq!(RecvError::Lagged(1), RecvError::Lagged(1));
+        assert_ne!(RecvError::Lagged(1), RecvError::Lagged(2));
+        assert_ne!(RecvError::Lagged(1), RecvError::Closed);
+    }
+
+    #[test]
+    fn test_recv_timeout_error_partial_eq() {
+        assert_eq!(RecvTimeoutError::Timeout, RecvTimeoutError::Timeout);
+        assert_eq!(RecvTimeoutError::Closed, RecvTimeoutError::Closed);
+        assert_eq!(RecvTimeoutError::Closed, RecvTimeoutError::Disconnected);
+        assert_eq!(
+            RecvTimeoutError::Disconnected,
+            RecvTimeoutError::Disconnected
+        );
+        assert_ne!(RecvTimeoutError::Timeout, RecvTimeoutError::Closed);
+        assert_ne!(RecvTimeoutError::Timeout, RecvTimeoutError::Disconnected);
     }
 }
@@ -0,0 +1,175 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+
+pub use async_broadcast::Receiver as AsyncBroadcastReceiver;
+use async_broadcast::{RecvError as RecvErrorInner, TryRecvError as TryRecvErrorInner};
+
+//
+mod broadcast_impl {
+    use super::*;
+
+    use crate::{
+        broadcast::{BroadcastReceiver, CloneableBroadcastReceiver},
+        error::{RecvError, TryRecvError},
+    };
+
+    #[async_trait::async_trait]
+    impl<T: Clone + Send> BroadcastReceiver<T> for AsyncBroadcastReceiver<T> {
+        async fn recv(&mut self) -> Result<T, RecvError>
+        where
+            T: Send,
+        {
+            AsyncBroadcastReceiver::recv(self).await.map_err(Into::into)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            AsyncBroadcastReceiver::try_recv(self).map_err(Into::into)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<T: Clone + Send> CloneableBroadcastReceiver<T> for AsyncBroadcastReceiver<T> {
+        async fn recv(&mut self) -> Result<T, RecvError>
+        where
+            T: Send,
+        {
+            AsyncBroadcastReceiver::recv(self).await.map_err(Into::into)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            AsyncBroadcastReceiver::try_recv(self).map_err(Into::into)
+        }
+    }
+}
+
+//
+mod poll_impl {
+    use super::*;
+
+    use crate::poll::PollReceiver;
+
+    // As a stream, values skipped by an overflow are passed over silently.
+    impl<T: Clone> PollReceiver<T> for AsyncBroadcastReceiver<T> {
+        fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+            Stream::poll_next(Pin::new(self), cx)
+        }
+    }
+}
+
+//
+mod introspect_impl {
+    use super::*;
+
+    use crate::introspect::Introspect;
+
+    impl<T> Introspect for AsyncBroadcastReceiver<T> {
+        fn len(&self) -> Option<usize> {
+            Some(AsyncBroadcastReceiver::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(AsyncBroadcastReceiver::is_empty(self))
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            Some(AsyncBroadcastReceiver::capacity(self).saturating_sub(self.len()))
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            Some(AsyncBroadcastReceiver::capacity(self))
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(AsyncBroadcastReceiver::is_closed(self))
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(AsyncBroadcastReceiver::sender_count(self))
+        }
+
+        fn receiver_count(&self) -> Option<usize> {
+            Some(AsyncBroadcastReceiver::receiver_count(self))
+        }
+    }
+}
+
+//
+mod error_convert {
+    use super::*;
+
+    use crate::error::{RecvError, TryRecvError};
+
+    impl From<TryRecvErrorInner> for TryRecvError {
+        fn from(err: TryRecvErrorInner) -> Self {
+            match err {
+                TryRecvErrorInner::Empty => Self::Empty,
+                TryRecvErrorInner::Closed => Self::Closed,
+                TryRecvErrorInner::Overflowed(n) => Self::Lagged(n),
+            }
+        }
+    }
+
+    impl From<RecvErrorInner> for RecvError {
+        fn from(err: RecvErrorInner) -> Self {
+            match err {
+                RecvErrorInner::Closed => Self::Closed,
+                RecvErrorInner::Overflowed(n) => Self::Lagged(n),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod broadcast_impl_tests {
+    use crate::{
+        broadcast::{BroadcastReceiver, CloneableBroadcastReceiver},
+        error::{RecvError, TryRecvError},
+    };
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (mut tx, rx) = async_broadcast::broadcast(2);
+            tx.set_overflow(true);
+            let mut receiver: Box<dyn BroadcastReceiver<usize> + Send> = Box::new(rx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+
+            assert_eq!(tx.broadcast(1).await, Ok(None));
+            assert_eq!(receiver.recv().await, Ok(1));
+
+            assert_eq!(tx.broadcast(2).await, Ok(None));
+            assert_eq!(tx.broadcast(3).await, Ok(None));
+            assert_eq!(tx.broadcast(4).await, Ok(Some(2)));
+            assert_eq!(receiver.len(), Some(2));
+            assert_eq!(receiver.recv().await, Err(RecvError::Lagged(1)));
+            assert_eq!(receiver.recv().await, Ok(3));
+
+            drop(tx);
+            assert_eq!(receiver.recv().await, Ok(4));
+            assert_eq!(receiver.recv().await, Err(RecvError::Closed));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cloneable_with_channel() {
+        {
+            let (tx, rx) = async_broadcast::broadcast(2);
+            let mut receiver: Box<dyn CloneableBroadcastReceiver<usize> + Send> = Box::new(rx);
+            assert_eq!(tx.broadcast(1).await, Ok(None));
+
+            let mut cloned = receiver.clone();
+            assert_eq!(receiver.receiver_count(), Some(2));
+            assert_eq!(receiver.recv().await, Ok(1));
+            assert_eq!(cloned.try_recv(), Ok(1));
+
+            assert_eq!(tx.broadcast(2).await, Ok(None));
+            drop(receiver);
+            assert_eq!(tx.receiver_count(), 1);
+            assert_eq!(cloned.recv().await, Ok(2));
+        }
+    }
+}
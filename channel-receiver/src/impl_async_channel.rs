@@ -1,3 +1,13 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use std::time::Instant;
+
+use futures_core::Stream;
+
 pub use async_channel::Receiver as AsyncChannelReceiver;
 use async_channel::TryRecvError as TryRecvErrorInner;
 
@@ -5,20 +15,24 @@ use async_channel::TryRecvError as TryRecvErrorInner;
 mod multi_consumer_impl {
     use super::*;
 
-    use crate::{error::TryRecvError, multi_consumer::AsyncReceiver};
+    use crate::{error::TryRecvError, multi_consumer::NativeAsyncReceiver, poll::PollReceiver};
 
-    #[async_trait::async_trait]
-    impl<T> AsyncReceiver<T> for AsyncChannelReceiver<T> {
-        async fn recv(&mut self) -> Option<T>
+    impl<T> NativeAsyncReceiver<T> for AsyncChannelReceiver<T> {
+        fn recv(&mut self) -> impl Future<Output = Option<T>> + Send + '_
         where
             T: Send,
         {
-            AsyncChannelReceiver::recv(self).await.ok()
+            PollReceiver::recv(self)
         }
 
         fn try_recv(&mut self) -> Result<T, TryRecvError> {
             AsyncChannelReceiver::try_recv(self).map_err(Into::into)
         }
+
+        fn close(&mut self) -> bool {
+            AsyncChannelReceiver::close(self);
+            true
+        }
     }
 }
 
@@ -26,20 +40,143 @@ mod multi_consumer_impl {
 mod generic_impl {
     use super::*;
 
-    use crate::{error::TryRecvError, generic::AsyncReceiver};
+    use crate::{error::TryRecvError, generic::NativeAsyncReceiver, poll::PollReceiver};
 
-    #[async_trait::async_trait]
-    impl<T> AsyncReceiver<T> for AsyncChannelReceiver<T> {
-        async fn recv(&mut self) -> Option<T>
+    impl<T> NativeAsyncReceiver<T> for AsyncChannelReceiver<T> {
+        fn recv(&mut self) -> impl Future<Output = Option<T>> + Send + '_
         where
             T: Send,
         {
-            AsyncChannelReceiver::recv(self).await.ok()
+            PollReceiver::recv(self)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            AsyncChannelReceiver::try_recv(self).map_err(Into::into)
+        }
+
+        fn close(&mut self) -> bool {
+            AsyncChannelReceiver::close(self);
+            true
+        }
+    }
+}
+
+//
+mod blocking_impl {
+    use super::*;
+
+    use crate::{
+        blocking::{block_on::recv_deadline, BlockingReceiver, CloneableBlockingReceiver},
+        error::{RecvTimeoutError, TryRecvError},
+    };
+
+    impl<T> BlockingReceiver<T> for AsyncChannelReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            AsyncChannelReceiver::recv_blocking(self).ok()
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            recv_deadline(
+                async { AsyncChannelReceiver::recv(self).await.ok() },
+                Instant::now().checked_add(timeout),
+            )
+        }
+
+        fn recv_deadline(&mut self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+            recv_deadline(
+                async { AsyncChannelReceiver::recv(self).await.ok() },
+                Some(deadline),
+            )
         }
 
         fn try_recv(&mut self) -> Result<T, TryRecvError> {
             AsyncChannelReceiver::try_recv(self).map_err(Into::into)
         }
+
+        fn close(&mut self) -> bool {
+            AsyncChannelReceiver::close(self);
+            true
+        }
+    }
+
+    impl<T> CloneableBlockingReceiver<T> for AsyncChannelReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            AsyncChannelReceiver::recv_blocking(self).ok()
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            recv_deadline(
+                async { AsyncChannelReceiver::recv(self).await.ok() },
+                Instant::now().checked_add(timeout),
+            )
+        }
+
+        fn recv_deadline(&mut self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+            recv_deadline(
+                async { AsyncChannelReceiver::recv(self).await.ok() },
+                Some(deadline),
+            )
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            AsyncChannelReceiver::try_recv(self).map_err(Into::into)
+        }
+
+        fn close(&mut self) -> bool {
+            AsyncChannelReceiver::close(self);
+            true
+        }
+    }
+}
+
+//
+mod poll_impl {
+    use super::*;
+
+    use crate::poll::PollReceiver;
+
+    impl<T> PollReceiver<T> for AsyncChannelReceiver<T> {
+        fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+            // The receiver keeps its listener between polls, so no per-call future is needed.
+            Stream::poll_next(Pin::new(self), cx)
+        }
+    }
+}
+
+//
+mod introspect_impl {
+    use super::*;
+
+    use crate::introspect::Introspect;
+
+    impl<T> Introspect for AsyncChannelReceiver<T> {
+        fn len(&self) -> Option<usize> {
+            Some(AsyncChannelReceiver::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(AsyncChannelReceiver::is_empty(self))
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            AsyncChannelReceiver::capacity(self).map(|cap| cap.saturating_sub(self.len()))
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            AsyncChannelReceiver::capacity(self)
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(AsyncChannelReceiver::is_closed(self))
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(AsyncChannelReceiver::sender_count(self))
+        }
+
+        fn receiver_count(&self) -> Option<usize> {
+            Some(AsyncChannelReceiver::receiver_count(self))
+        }
     }
 }
 
@@ -89,6 +226,89 @@ mod multi_consumer_impl_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_recv_many_with_bounded() {
+        {
+            let (tx, rx) = async_channel::bounded(4);
+            let receiver: Box<dyn AsyncReceiver<usize> + Send> = Box::new(rx);
+            let mut receiver = receiver.clone();
+            let mut buf = vec![];
+            assert_eq!(receiver.recv_many(&mut buf, 0).await, 0);
+            assert_eq!(
+                receiver.try_recv_many(&mut buf, 2),
+                Err(TryRecvError::Empty)
+            );
+            for i in 1..=4 {
+                assert_eq!(tx.send(i).await, Ok(()));
+            }
+            assert_eq!(receiver.recv_many(&mut buf, 3).await, 3);
+            assert_eq!(buf, vec![1, 2, 3]);
+            assert_eq!(receiver.recv_many(&mut buf, 3).await, 1);
+            assert_eq!(buf, vec![1, 2, 3, 4]);
+            let handle = tokio::spawn(async move {
+                tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+                assert_eq!(tx.send(5).await, Ok(()));
+                tx
+            });
+            buf.clear();
+            assert_eq!(receiver.recv_many(&mut buf, 3).await, 1);
+            assert_eq!(buf, vec![5]);
+            let tx = handle.await.unwrap();
+            assert_eq!(tx.send(6).await, Ok(()));
+            assert_eq!(tx.send(7).await, Ok(()));
+            drop(tx);
+            assert_eq!(receiver.try_recv_many(&mut buf, 1), Ok(1));
+            assert_eq!(receiver.try_recv_many(&mut buf, 3), Ok(1));
+            assert_eq!(buf, vec![5, 6, 7]);
+            assert_eq!(
+                receiver.try_recv_many(&mut buf, 3),
+                Err(TryRecvError::Closed)
+            );
+            assert_eq!(receiver.recv_many(&mut buf, 3).await, 0);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recv_timeout_with_manual_timer() {
+        use core::time::Duration;
+
+        use crate::{error::RecvTimeoutError, timer::ManualTimer};
+
+        {
+            let (tx, rx) = async_channel::bounded(1);
+            let receiver: Box<dyn AsyncReceiver<usize> + Send> = Box::new(rx);
+            let timer = ManualTimer::new();
+            let handle = tokio::spawn({
+                let mut receiver = receiver.clone();
+                let timer = timer.clone();
+                async move {
+                    (
+                        receiver.recv_timeout(Duration::from_secs(60), &timer).await,
+                        receiver.recv_timeout(Duration::from_secs(60), &timer).await,
+                    )
+                }
+            });
+            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+            assert_eq!(tx.send(1).await, Ok(()));
+            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+            timer.advance(Duration::from_secs(30));
+            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+            assert!(!handle.is_finished());
+            timer.advance(Duration::from_secs(30));
+            assert_eq!(
+                handle.await.unwrap(),
+                (Ok(1), Err(RecvTimeoutError::Timeout))
+            );
+
+            let mut receiver = receiver.clone();
+            drop(tx);
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_secs(60), &timer).await,
+                Err(RecvTimeoutError::Closed)
+            );
+        }
+    }
+
     #[tokio::test]
     async fn test_with_unbounded() {
         {
@@ -170,3 +390,190 @@ mod generic_impl_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod poll_impl_tests {
+    use core::task::Poll;
+
+    use futures_util::future;
+
+    use crate::poll::PollReceiver;
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = async_channel::bounded(1);
+            let mut receiver: Box<dyn PollReceiver<usize> + Send> = Box::new(rx.clone());
+            assert!(future::poll_fn(|cx| Poll::Ready(receiver.poll_recv(cx).is_pending())).await);
+            let handle = tokio::spawn(async move {
+                for i in 1..=2 {
+                    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+                    assert_eq!(tx.send(i).await, Ok(()));
+                }
+            });
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(future::poll_fn(|cx| receiver.poll_recv(cx)).await, Some(2));
+            handle.await.unwrap();
+            assert_eq!(receiver.recv().await, None);
+            drop(rx);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded() {
+        {
+            let (tx, mut rx) = async_channel::unbounded();
+            assert_eq!(tx.send(1).await, Ok(()));
+            assert_eq!(PollReceiver::recv(&mut rx).await, Some(1));
+            drop(tx);
+            assert_eq!(PollReceiver::recv(&mut rx).await, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod blocking_impl_tests {
+    use core::time::Duration;
+    use std::time::Instant;
+
+    use crate::{
+        blocking::{BlockingReceiver, CloneableBlockingReceiver},
+        error::{RecvTimeoutError, TryRecvError},
+    };
+
+    #[test]
+    fn test_with_bounded() {
+        {
+            let (tx, rx) = async_channel::bounded(1);
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send_blocking(1), Ok(()));
+            assert_eq!(receiver.recv(), Some(1));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(
+                receiver.recv_deadline(Instant::now() + Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Closed));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Closed)
+            );
+            assert_eq!(receiver.recv(), None);
+        }
+        {
+            let (tx, rx) = async_channel::bounded(1);
+            let receiver: Box<dyn CloneableBlockingReceiver<usize>> = Box::new(rx);
+            let mut receiver = receiver.clone();
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(100));
+                assert_eq!(tx.send_blocking(1), Ok(()));
+            });
+            assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(1));
+            handle.join().unwrap();
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Closed));
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+
+    #[test]
+    fn test_with_unbounded() {
+        {
+            let (tx, rx) = async_channel::unbounded();
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send_blocking(1), Ok(()));
+            assert_eq!(receiver.recv(), Some(1));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(
+                receiver.recv_deadline(Instant::now() + Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Closed));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Closed)
+            );
+            assert_eq!(receiver.recv(), None);
+        }
+        {
+            let (tx, rx) = async_channel::unbounded();
+            let receiver: Box<dyn CloneableBlockingReceiver<usize>> = Box::new(rx);
+            let mut receiver = receiver.clone();
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(100));
+                assert_eq!(tx.send_blocking(1), Ok(()));
+            });
+            assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(1));
+            handle.join().unwrap();
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Closed));
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod introspect_impl_tests {
+    use crate::multi_consumer::AsyncReceiver;
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = async_channel::bounded(2);
+            let receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            let mut receiver = receiver.clone();
+            assert_eq!(tx.send(1).await, Ok(()));
+            assert_eq!(receiver.len(), Some(1));
+            assert_eq!(receiver.capacity(), Some(1));
+            assert_eq!(receiver.max_capacity(), Some(2));
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(receiver.is_empty(), Some(true));
+            assert_eq!(receiver.is_closed(), Some(false));
+            drop(tx);
+            assert_eq!(receiver.is_closed(), Some(true));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_counts_with_bounded() {
+        {
+            let (tx, rx) = async_channel::bounded::<usize>(1);
+            let receiver: Box<dyn AsyncReceiver<usize> + Send> = Box::new(rx);
+            let cloned = receiver.clone();
+            assert_eq!(receiver.sender_count(), Some(1));
+            assert_eq!(receiver.receiver_count(), Some(2));
+            drop(cloned);
+            drop(tx);
+            assert_eq!(receiver.sender_count(), Some(0));
+            assert_eq!(receiver.receiver_count(), Some(1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod close_impl_tests {
+    use crate::multi_consumer::AsyncReceiver;
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = async_channel::bounded(2);
+            let receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            let mut other = receiver.clone();
+            assert_eq!(tx.send(1).await, Ok(()));
+            assert!(other.close());
+            assert!(tx.send(2).await.is_err());
+            let mut receiver = receiver;
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(receiver.recv().await, None);
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use core::time::Duration;
+use std::time::Instant;
+
+pub use crossbeam_channel::Receiver as CrossbeamReceiver;
+use crossbeam_channel::{
+    RecvTimeoutError as RecvTimeoutErrorInner, TryRecvError as TryRecvErrorInner,
+};
+
+//
+mod blocking_impl {
+    use super::*;
+
+    use crate::{
+        blocking::{BlockingReceiver, CloneableBlockingReceiver},
+        error::{RecvTimeoutError, TryRecvError},
+    };
+
+    impl<T> BlockingReceiver<T> for CrossbeamReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            CrossbeamReceiver::recv(self).ok()
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            CrossbeamReceiver::recv_timeout(self, timeout).map_err(Into::into)
+        }
+
+        fn recv_deadline(&mut self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+            CrossbeamReceiver::recv_deadline(self, deadline).map_err(Into::into)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            CrossbeamReceiver::try_recv(self).map_err(Into::into)
+        }
+    }
+
+    impl<T> CloneableBlockingReceiver<T> for CrossbeamReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            CrossbeamReceiver::recv(self).ok()
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            CrossbeamReceiver::recv_timeout(self, timeout).map_err(Into::into)
+        }
+
+        fn recv_deadline(&mut self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+            CrossbeamReceiver::recv_deadline(self, deadline).map_err(Into::into)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            CrossbeamReceiver::try_recv(self).map_err(Into::into)
+        }
+    }
+}
+
+//
+mod introspect_impl {
+    use super::*;
+
+    use core::any::Any;
+
+    use crate::introspect::{downcast, Introspect};
+
+    // crossbeam-channel has no way to check for a disconnect without receiving.
+    impl<T> Introspect for CrossbeamReceiver<T> {
+        fn len(&self) -> Option<usize> {
+            Some(CrossbeamReceiver::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(CrossbeamReceiver::is_empty(self))
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            CrossbeamReceiver::capacity(self).map(|cap| cap.saturating_sub(self.len()))
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            CrossbeamReceiver::capacity(self)
+        }
+
+        fn as_any(&self) -> Option<&dyn Any>
+        where
+            Self: 'static,
+        {
+            Some(self)
+        }
+
+        fn same_channel(&self, other: &(dyn Introspect + 'static)) -> Option<bool>
+        where
+            Self: 'static,
+        {
+            Some(
+                downcast::<Self>(other)
+                    .is_some_and(|other| CrossbeamReceiver::same_channel(self, other)),
+            )
+        }
+    }
+}
+
+//
+mod error_convert {
+    use super::*;
+
+    use crate::error::{RecvTimeoutError, TryRecvError};
+
+    impl From<TryRecvErrorInner> for TryRecvError {
+        fn from(err: TryRecvErrorInner) -> Self {
+            match err {
+                TryRecvErrorInner::Empty => Self::Empty,
+                TryRecvErrorInner::Disconnected => Self::Disconnected,
+            }
+        }
+    }
+
+    impl From<RecvTimeoutErrorInner> for RecvTimeoutError {
+        fn from(err: RecvTimeoutErrorInner) -> Self {
+            match err {
+                RecvTimeoutErrorInner::Timeout => Self::Timeout,
+                RecvTimeoutErrorInner::Disconnected => Self::Disconnected,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod blocking_impl_tests {
+    use core::time::Duration;
+    use std::time::Instant;
+
+    use crate::{
+        blocking::{BlockingReceiver, CloneableBlockingReceiver},
+        error::{RecvTimeoutError, TryRecvError},
+    };
+
+    #[test]
+    fn test_with_bounded() {
+        {
+            let (tx, rx) = crossbeam_channel::bounded(1);
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send(1), Ok(()));
+            assert_eq!(receiver.recv(), Some(1));
+            assert_eq!(tx.try_send(2), Ok(()));
+            assert_eq!(receiver.recv(), Some(2));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Disconnected)
+            );
+            assert_eq!(receiver.recv(), None);
+        }
+        {
+            let (tx, rx) = crossbeam_channel::bounded(1);
+            let receiver: Box<dyn CloneableBlockingReceiver<usize>> = Box::new(rx);
+            let mut receiver = receiver.clone();
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(100));
+                assert_eq!(tx.send(1), Ok(()));
+            });
+            assert_eq!(
+                receiver.recv_deadline(Instant::now() + Duration::from_secs(5)),
+                Ok(1)
+            );
+            handle.join().unwrap();
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+
+    #[test]
+    fn test_with_unbounded() {
+        {
+            let (tx, rx) = crossbeam_channel::unbounded();
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send(1), Ok(()));
+            assert_eq!(tx.send(2), Ok(()));
+            assert_eq!(receiver.recv(), Some(1));
+            assert_eq!(receiver.recv(), Some(2));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Disconnected)
+            );
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+}
@@ -0,0 +1,373 @@
+use core::time::Duration;
+use std::time::Instant;
+
+pub use flume::Receiver as FlumeReceiver;
+use flume::{RecvTimeoutError as RecvTimeoutErrorInner, TryRecvError as TryRecvErrorInner};
+
+//
+mod multi_consumer_impl {
+    use super::*;
+
+    use crate::{error::TryRecvError, multi_consumer::NativeAsyncReceiver};
+
+    impl<T> NativeAsyncReceiver<T> for FlumeReceiver<T> {
+        async fn recv(&mut self) -> Option<T>
+        where
+            T: Send,
+        {
+            FlumeReceiver::recv_async(self).await.ok()
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            FlumeReceiver::try_recv(self).map_err(Into::into)
+        }
+    }
+}
+
+//
+mod generic_impl {
+    use super::*;
+
+    use crate::{error::TryRecvError, generic::NativeAsyncReceiver};
+
+    impl<T> NativeAsyncReceiver<T> for FlumeReceiver<T> {
+        async fn recv(&mut self) -> Option<T>
+        where
+            T: Send,
+        {
+            FlumeReceiver::recv_async(self).await.ok()
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            FlumeReceiver::try_recv(self).map_err(Into::into)
+        }
+    }
+}
+
+//
+mod blocking_impl {
+    use super::*;
+
+    use crate::{
+        blocking::{BlockingReceiver, CloneableBlockingReceiver},
+        error::{RecvTimeoutError, TryRecvError},
+    };
+
+    impl<T> BlockingReceiver<T> for FlumeReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            FlumeReceiver::recv(self).ok()
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            FlumeReceiver::recv_timeout(self, timeout).map_err(Into::into)
+        }
+
+        fn recv_deadline(&mut self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+            FlumeReceiver::recv_deadline(self, deadline).map_err(Into::into)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            FlumeReceiver::try_recv(self).map_err(Into::into)
+        }
+    }
+
+    impl<T> CloneableBlockingReceiver<T> for FlumeReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            FlumeReceiver::recv(self).ok()
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            FlumeReceiver::recv_timeout(self, timeout).map_err(Into::into)
+        }
+
+        fn recv_deadline(&mut self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+            FlumeReceiver::recv_deadline(self, deadline).map_err(Into::into)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            FlumeReceiver::try_recv(self).map_err(Into::into)
+        }
+    }
+}
+
+//
+mod introspect_impl {
+    use super::*;
+
+    use core::any::Any;
+
+    use crate::introspect::{downcast, Introspect};
+
+    impl<T> Introspect for FlumeReceiver<T> {
+        fn len(&self) -> Option<usize> {
+            Some(FlumeReceiver::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(FlumeReceiver::is_empty(self))
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            FlumeReceiver::capacity(self).map(|cap| cap.saturating_sub(self.len()))
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            FlumeReceiver::capacity(self)
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(FlumeReceiver::is_disconnected(self))
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(FlumeReceiver::sender_count(self))
+        }
+
+        fn receiver_count(&self) -> Option<usize> {
+            Some(FlumeReceiver::receiver_count(self))
+        }
+
+        fn as_any(&self) -> Option<&dyn Any>
+        where
+            Self: 'static,
+        {
+            Some(self)
+        }
+
+        fn same_channel(&self, other: &(dyn Introspect + 'static)) -> Option<bool>
+        where
+            Self: 'static,
+        {
+            Some(
+                downcast::<Self>(other)
+                    .is_some_and(|other| FlumeReceiver::same_channel(self, other)),
+            )
+        }
+    }
+}
+
+//
+mod error_convert {
+    use super::*;
+
+    use crate::error::{RecvTimeoutError, TryRecvError};
+
+    impl From<TryRecvErrorInner> for TryRecvError {
+        fn from(err: TryRecvErrorInner) -> Self {
+            match err {
+                TryRecvErrorInner::Empty => Self::Empty,
+                TryRecvErrorInner::Disconnected => Self::Disconnected,
+            }
+        }
+    }
+
+    impl From<RecvTimeoutErrorInner> for RecvTimeoutError {
+        fn from(err: RecvTimeoutErrorInner) -> Self {
+            match err {
+                RecvTimeoutErrorInner::Timeout => Self::Timeout,
+                RecvTimeoutErrorInner::Disconnected => Self::Disconnected,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod multi_consumer_impl_tests {
+    use crate::{error::TryRecvError, multi_consumer::AsyncReceiver};
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = flume::bounded(1);
+            let receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            let mut receiver = receiver.clone();
+            assert_eq!(tx.send_async(1).await, Ok(()));
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(tx.try_send(2), Ok(()));
+            assert_eq!(receiver.recv().await, Some(2));
+            assert!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), receiver.recv())
+                    .await
+                    .is_err()
+            );
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), receiver.recv())
+                    .await,
+                Ok(None)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded() {
+        {
+            let (tx, rx) = flume::unbounded();
+            let receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            let mut receiver = receiver.clone();
+            assert_eq!(tx.send_async(1).await, Ok(()));
+            assert_eq!(tx.send_async(2).await, Ok(()));
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(receiver.recv().await, Some(2));
+            assert!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), receiver.recv())
+                    .await
+                    .is_err()
+            );
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), receiver.recv())
+                    .await,
+                Ok(None)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod generic_impl_tests {
+    use crate::{error::TryRecvError, generic::AsyncReceiver};
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = flume::bounded(1);
+            let mut receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send_async(1).await, Ok(()));
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(tx.try_send(2), Ok(()));
+            assert_eq!(receiver.recv().await, Some(2));
+            assert!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), receiver.recv())
+                    .await
+                    .is_err()
+            );
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), receiver.recv())
+                    .await,
+                Ok(None)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded() {
+        {
+            let (tx, rx) = flume::unbounded();
+            let mut receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send_async(1).await, Ok(()));
+            assert_eq!(tx.send_async(2).await, Ok(()));
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(receiver.recv().await, Some(2));
+            assert!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), receiver.recv())
+                    .await
+                    .is_err()
+            );
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), receiver.recv())
+                    .await,
+                Ok(None)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod blocking_impl_tests {
+    use core::time::Duration;
+    use std::time::Instant;
+
+    use crate::{
+        blocking::{BlockingReceiver, CloneableBlockingReceiver},
+        error::{RecvTimeoutError, TryRecvError},
+    };
+
+    #[test]
+    fn test_with_bounded() {
+        {
+            let (tx, rx) = flume::bounded(1);
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send(1), Ok(()));
+            assert_eq!(receiver.recv(), Some(1));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(
+                receiver.recv_deadline(Instant::now() + Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Disconnected)
+            );
+            assert_eq!(receiver.recv(), None);
+        }
+        {
+            let (tx, rx) = flume::bounded(1);
+            let receiver: Box<dyn CloneableBlockingReceiver<usize>> = Box::new(rx);
+            let mut receiver = receiver.clone();
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(100));
+                assert_eq!(tx.send(1), Ok(()));
+            });
+            assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(1));
+            handle.join().unwrap();
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+
+    #[test]
+    fn test_with_unbounded() {
+        {
+            let (tx, rx) = flume::unbounded();
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send(1), Ok(()));
+            assert_eq!(receiver.recv(), Some(1));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(
+                receiver.recv_deadline(Instant::now() + Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Disconnected)
+            );
+            assert_eq!(receiver.recv(), None);
+        }
+        {
+            let (tx, rx) = flume::unbounded();
+            let receiver: Box<dyn CloneableBlockingReceiver<usize>> = Box::new(rx);
+            let mut receiver = receiver.clone();
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(100));
+                assert_eq!(tx.send(1), Ok(()));
+            });
+            assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(1));
+            handle.join().unwrap();
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_channel::mpsc::TryRecvError as MpscTryRecvErrorInner;
+pub use futures_channel::{
+    mpsc::{Receiver as FuturesMpscReceiver, UnboundedReceiver as FuturesMpscUnboundedReceiver},
+    oneshot::Receiver as FuturesOneshotReceiver,
+};
+use futures_core::Stream;
+
+//
+pub struct FuturesOneshotReceiverWrapper<T>(pub FuturesOneshotReceiver<T>);
+
+//
+mod single_consumer_impl {
+    use super::*;
+
+    use crate::{error::TryRecvError, single_consumer::NativeAsyncReceiver};
+
+    impl<T> NativeAsyncReceiver<T> for FuturesMpscReceiver<T> {
+        async fn recv(&mut self) -> Option<T>
+        where
+            T: Send,
+        {
+            FuturesMpscReceiver::recv(self).await.ok()
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            FuturesMpscReceiver::try_recv(self).map_err(Into::into)
+        }
+
+        fn close(&mut self) -> bool {
+            FuturesMpscReceiver::close(self);
+            true
+        }
+    }
+
+    impl<T> NativeAsyncReceiver<T> for FuturesMpscUnboundedReceiver<T> {
+        async fn recv(&mut self) -> Option<T>
+        where
+            T: Send,
+        {
+            FuturesMpscUnboundedReceiver::recv(self).await.ok()
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            FuturesMpscUnboundedReceiver::try_recv(self).map_err(Into::into)
+        }
+
+        fn close(&mut self) -> bool {
+            FuturesMpscUnboundedReceiver::close(self);
+            true
+        }
+    }
+}
+
+//
+mod generic_impl {
+    use super::*;
+
+    use crate::{error::TryRecvError, generic::NativeAsyncReceiver};
+
+    impl<T> NativeAsyncReceiver<T> for FuturesMpscReceiver<T> {
+        async fn recv(&mut self) -> Option<T>
+        where
+            T: Send,
+        {
+            FuturesMpscReceiver::recv(self).await.ok()
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            FuturesMpscReceiver::try_recv(self).map_err(Into::into)
+        }
+
+        fn close(&mut self) -> bool {
+            FuturesMpscReceiver::close(self);
+            true
+        }
+    }
+
+    impl<T> NativeAsyncReceiver<T> for FuturesMpscUnboundedReceiver<T> {
+        async fn recv(&mut self) -> Option<T>
+        where
+            T: Send,
+        {
+            FuturesMpscUnboundedReceiver::recv(self).await.ok()
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            FuturesMpscUnboundedReceiver::try_recv(self).map_err(Into::into)
+        }
+
+        fn close(&mut self) -> bool {
+            FuturesMpscUnboundedReceiver::close(self);
+            true
+        }
+    }
+}
+
+//
+mod poll_impl {
+    use super::*;
+
+    use crate::poll::PollReceiver;
+
+    impl<T> PollReceiver<T> for FuturesMpscReceiver<T> {
+        fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+            Stream::poll_next(Pin::new(self), cx)
+        }
+    }
+
+    impl<T> PollReceiver<T> for FuturesMpscUnboundedReceiver<T> {
+        fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+            Stream::poll_next(Pin::new(self), cx)
+        }
+    }
+}
+
+//
+mod one_shot_impl {
+    use super::*;
+
+    use crate::{
+        error::{OneshotRecvError, TryRecvError},
+        one_shot::AsyncReceiver,
+    };
+
+    impl<T> Future for FuturesOneshotReceiverWrapper<T> {
+        type Output = Result<T, OneshotRecvError>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let ret = futures_core::ready!(Pin::new(&mut self.as_mut().0).poll(cx));
+            Poll::Ready(ret.map_err(|_| OneshotRecvError::Dropped))
+        }
+    }
+
+    impl<T> AsyncReceiver<T> for FuturesOneshotReceiverWrapper<T> {
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            match FuturesOneshotReceiver::try_recv(&mut self.0) {
+                Ok(Some(t)) => Ok(t),
+                Ok(None) => Err(TryRecvError::Empty),
+                Err(_) => Err(TryRecvError::Closed),
+            }
+        }
+    }
+}
+
+//
+mod introspect_impl {
+    use super::*;
+
+    use crate::introspect::Introspect;
+
+    // futures-channel exposes none of these on the receiving side.
+    impl<T> Introspect for FuturesMpscReceiver<T> {}
+
+    impl<T> Introspect for FuturesMpscUnboundedReceiver<T> {}
+}
+
+//
+mod error_convert {
+    use super::*;
+
+    use crate::error::TryRecvError;
+
+    impl From<MpscTryRecvErrorInner> for TryRecvError {
+        fn from(err: MpscTryRecvErrorInner) -> Self {
+            match err {
+                MpscTryRecvErrorInner::Empty => Self::Empty,
+                MpscTryRecvErrorInner::Closed => Self::Closed,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod single_consumer_impl_tests {
+    use crate::{error::TryRecvError, single_consumer::AsyncReceiver};
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (mut tx, rx) = futures_channel::mpsc::channel(1);
+            let mut receiver: Box<dyn AsyncReceiver<usize> + Send> = Box::new(rx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            assert!(tx.try_send(1).is_ok());
+            assert_eq!(receiver.recv().await, Some(1));
+            assert!(tx.try_send(2).is_ok());
+            assert!(receiver.close());
+            assert!(tx.try_send(3).is_err());
+            assert_eq!(receiver.recv().await, Some(2));
+            assert_eq!(receiver.recv().await, None);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Closed));
+        }
+        {
+            let (tx, rx) = futures_channel::mpsc::unbounded();
+            let mut receiver: Box<dyn AsyncReceiver<usize> + Send> = Box::new(rx);
+            assert!(tx.unbounded_send(1).is_ok());
+            assert!(tx.unbounded_send(2).is_ok());
+            let mut buf = vec![];
+            assert_eq!(receiver.recv_many(&mut buf, 10).await, 2);
+            assert_eq!(buf, vec![1, 2]);
+            drop(tx);
+            assert_eq!(receiver.recv().await, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod generic_impl_tests {
+    use crate::{error::TryRecvError, generic::AsyncReceiver};
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, rx) = futures_channel::mpsc::unbounded();
+            let mut receiver: Box<dyn AsyncReceiver<usize> + Send> = Box::new(rx);
+            assert!(tx.unbounded_send(1).is_ok());
+            assert_eq!(receiver.try_recv(), Ok(1));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.recv().await, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod one_shot_impl_tests {
+    use super::*;
+
+    use crate::{
+        error::{OneshotRecvError, TryRecvError},
+        one_shot::AsyncReceiver,
+    };
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, rx) = futures_channel::oneshot::channel();
+            let mut receiver: Box<dyn AsyncReceiver<usize>> =
+                Box::new(FuturesOneshotReceiverWrapper(rx));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            assert!(tx.send(1).is_ok());
+            assert_eq!(receiver.try_recv(), Ok(1));
+
+            let (tx, rx) = futures_channel::oneshot::channel();
+            assert!(tx.send(1).is_ok());
+            let receiver: Box<dyn AsyncReceiver<usize>> =
+                Box::new(FuturesOneshotReceiverWrapper(rx));
+            assert_eq!(Box::into_pin(receiver).await, Ok(1));
+
+            let (tx, rx) = futures_channel::oneshot::channel::<usize>();
+            drop(tx);
+            let receiver: Box<dyn AsyncReceiver<usize>> =
+                Box::new(FuturesOneshotReceiverWrapper(rx));
+            assert_eq!(
+                Box::into_pin(receiver).await,
+                Err(OneshotRecvError::Dropped)
+            );
+        }
+    }
+}
@@ -0,0 +1,432 @@
+use core::time::Duration;
+
+// kanal 0.1 has no oneshot channel, only the bounded and unbounded flavours.
+pub use kanal::{AsyncReceiver as KanalAsyncReceiver, Receiver as KanalReceiver};
+use kanal::{ReceiveError as ReceiveErrorInner, ReceiveErrorTimeout as ReceiveErrorTimeoutInner};
+
+use crate::error::TryRecvError;
+
+//
+fn try_recv<T>(receiver: &KanalReceiver<T>) -> Result<T, TryRecvError> {
+    match KanalReceiver::try_recv(receiver) {
+        Ok(Some(t)) => Ok(t),
+        Ok(None) => Err(TryRecvError::Empty),
+        Err(err) => Err(err.into()),
+    }
+}
+
+//
+mod multi_consumer_impl {
+    use super::*;
+
+    use crate::multi_consumer::NativeAsyncReceiver;
+
+    impl<T> NativeAsyncReceiver<T> for KanalReceiver<T> {
+        async fn recv(&mut self) -> Option<T>
+        where
+            T: Send,
+        {
+            KanalReceiver::as_async(self).recv().await.ok()
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            try_recv(self)
+        }
+    }
+
+    impl<T> NativeAsyncReceiver<T> for KanalAsyncReceiver<T> {
+        async fn recv(&mut self) -> Option<T>
+        where
+            T: Send,
+        {
+            KanalAsyncReceiver::recv(self).await.ok()
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            try_recv(self.as_sync())
+        }
+    }
+}
+
+//
+mod generic_impl {
+    use super::*;
+
+    use crate::generic::NativeAsyncReceiver;
+
+    impl<T> NativeAsyncReceiver<T> for KanalReceiver<T> {
+        async fn recv(&mut self) -> Option<T>
+        where
+            T: Send,
+        {
+            KanalReceiver::as_async(self).recv().await.ok()
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            try_recv(self)
+        }
+    }
+
+    impl<T> NativeAsyncReceiver<T> for KanalAsyncReceiver<T> {
+        async fn recv(&mut self) -> Option<T>
+        where
+            T: Send,
+        {
+            KanalAsyncReceiver::recv(self).await.ok()
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            try_recv(self.as_sync())
+        }
+    }
+}
+
+//
+mod blocking_impl {
+    use super::*;
+
+    use crate::{
+        blocking::{BlockingReceiver, CloneableBlockingReceiver},
+        error::RecvTimeoutError,
+    };
+
+    impl<T> BlockingReceiver<T> for KanalReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            KanalReceiver::recv(self).ok()
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            KanalReceiver::recv_timeout(self, timeout).map_err(Into::into)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            try_recv(self)
+        }
+    }
+
+    impl<T> CloneableBlockingReceiver<T> for KanalReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            KanalReceiver::recv(self).ok()
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            KanalReceiver::recv_timeout(self, timeout).map_err(Into::into)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            try_recv(self)
+        }
+    }
+
+    impl<T> BlockingReceiver<T> for KanalAsyncReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            self.as_sync().recv().ok()
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            self.as_sync().recv_timeout(timeout).map_err(Into::into)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            try_recv(self.as_sync())
+        }
+    }
+
+    impl<T> CloneableBlockingReceiver<T> for KanalAsyncReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            self.as_sync().recv().ok()
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            self.as_sync().recv_timeout(timeout).map_err(Into::into)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            try_recv(self.as_sync())
+        }
+    }
+}
+
+//
+mod introspect_impl {
+    use super::*;
+
+    use crate::introspect::Introspect;
+
+    impl<T> Introspect for KanalReceiver<T> {
+        fn len(&self) -> Option<usize> {
+            Some(KanalReceiver::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(KanalReceiver::is_empty(self))
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            self.max_capacity()
+                .map(|cap| cap.saturating_sub(KanalReceiver::len(self)))
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            KanalReceiver::is_bounded(self).then(|| KanalReceiver::capacity(self))
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(KanalReceiver::is_disconnected(self))
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(KanalReceiver::sender_count(self) as usize)
+        }
+
+        fn receiver_count(&self) -> Option<usize> {
+            Some(KanalReceiver::receiver_count(self) as usize)
+        }
+    }
+
+    impl<T> Introspect for KanalAsyncReceiver<T> {
+        fn len(&self) -> Option<usize> {
+            Introspect::len(self.as_sync())
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Introspect::is_empty(self.as_sync())
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            Introspect::capacity(self.as_sync())
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            Introspect::max_capacity(self.as_sync())
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Introspect::is_closed(self.as_sync())
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Introspect::sender_count(self.as_sync())
+        }
+
+        fn receiver_count(&self) -> Option<usize> {
+            Introspect::receiver_count(self.as_sync())
+        }
+    }
+}
+
+//
+mod error_convert {
+    use super::*;
+
+    use crate::error::RecvTimeoutError;
+
+    impl From<ReceiveErrorInner> for TryRecvError {
+        fn from(err: ReceiveErrorInner) -> Self {
+            match err {
+                ReceiveErrorInner::Closed => Self::Closed,
+                ReceiveErrorInner::SendClosed => Self::Disconnected,
+            }
+        }
+    }
+
+    impl From<ReceiveErrorTimeoutInner> for RecvTimeoutError {
+        fn from(err: ReceiveErrorTimeoutInner) -> Self {
+            match err {
+                ReceiveErrorTimeoutInner::Timeout => Self::Timeout,
+                ReceiveErrorTimeoutInner::Closed => Self::Closed,
+                ReceiveErrorTimeoutInner::SendClosed => Self::Disconnected,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod multi_consumer_impl_tests {
+    use crate::{error::TryRecvError, multi_consumer::AsyncReceiver};
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = kanal::bounded_async(1);
+            let receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            let mut receiver = receiver.clone();
+            assert_eq!(tx.send(1).await, Ok(()));
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(tx.try_send(2), Ok(true));
+            assert_eq!(receiver.recv().await, Some(2));
+            assert!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), receiver.recv())
+                    .await
+                    .is_err()
+            );
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), receiver.recv())
+                    .await,
+                Ok(None)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded() {
+        {
+            let (tx, rx) = kanal::unbounded();
+            let receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            let mut receiver = receiver.clone();
+            assert_eq!(tx.send(1), Ok(()));
+            assert_eq!(tx.send(2), Ok(()));
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(receiver.recv().await, Some(2));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(receiver.recv().await, None);
+        }
+        {
+            let (tx, rx) = kanal::unbounded_async();
+            let mut receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send(1).await, Ok(()));
+            assert!(tx.close().is_ok());
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Closed));
+            assert_eq!(receiver.recv().await, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod generic_impl_tests {
+    use crate::{error::TryRecvError, generic::AsyncReceiver};
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = kanal::bounded_async(1);
+            let mut receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send(1).await, Ok(()));
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(receiver.recv().await, None);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded() {
+        {
+            let (tx, rx) = kanal::unbounded();
+            let mut receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send(1), Ok(()));
+            assert_eq!(tx.send(2), Ok(()));
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(receiver.recv().await, Some(2));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(receiver.recv().await, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod blocking_impl_tests {
+    use core::time::Duration;
+    use std::time::Instant;
+
+    use crate::{
+        blocking::{BlockingReceiver, CloneableBlockingReceiver},
+        error::{RecvTimeoutError, TryRecvError},
+    };
+
+    #[test]
+    fn test_with_bounded() {
+        {
+            let (tx, rx) = kanal::bounded(1);
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send(1), Ok(()));
+            assert_eq!(receiver.recv(), Some(1));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(
+                receiver.recv_deadline(Instant::now() + Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Disconnected)
+            );
+            assert_eq!(receiver.recv(), None);
+        }
+        {
+            let (tx, rx) = kanal::bounded_async(1);
+            let receiver: Box<dyn CloneableBlockingReceiver<usize>> = Box::new(rx);
+            let mut receiver = receiver.clone();
+            let tx = tx.to_sync();
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(100));
+                assert_eq!(tx.send(1), Ok(()));
+            });
+            assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(1));
+            handle.join().unwrap();
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+
+    #[test]
+    fn test_with_unbounded() {
+        {
+            let (tx, rx) = kanal::unbounded();
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert_eq!(tx.send(1), Ok(()));
+            assert_eq!(receiver.recv(), Some(1));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            assert!(tx.close().is_ok());
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Closed));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Closed)
+            );
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod introspect_impl_tests {
+    use crate::introspect::Introspect;
+
+    #[test]
+    fn test_with_bounded() {
+        let (tx, rx) = kanal::bounded_async::<usize>(2);
+        let rx2 = rx.clone();
+        assert_eq!(tx.try_send(1), Ok(true));
+        assert_eq!(Introspect::len(&rx), Some(1));
+        assert_eq!(Introspect::is_empty(&rx), Some(false));
+        assert_eq!(Introspect::capacity(&rx), Some(1));
+        assert_eq!(Introspect::max_capacity(&rx), Some(2));
+        assert_eq!(Introspect::sender_count(&rx), Some(1));
+        assert_eq!(Introspect::receiver_count(&rx), Some(2));
+        assert_eq!(Introspect::is_closed(&rx), Some(false));
+        drop(tx);
+        drop(rx2);
+        assert_eq!(Introspect::receiver_count(&rx), Some(1));
+        assert_eq!(Introspect::is_closed(&rx), Some(true));
+    }
+
+    #[test]
+    fn test_with_unbounded() {
+        let (_tx, rx) = kanal::unbounded::<usize>();
+        assert_eq!(Introspect::capacity(&rx), None);
+        assert_eq!(Introspect::max_capacity(&rx), None);
+    }
+}
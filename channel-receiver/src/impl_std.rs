@@ -0,0 +1,166 @@
+use core::time::Duration;
+
+pub use std::sync::mpsc::Receiver as StdMpscReceiver;
+use std::sync::mpsc::{
+    RecvTimeoutError as RecvTimeoutErrorInner, TryRecvError as TryRecvErrorInner,
+};
+
+//
+mod blocking_impl {
+    use super::*;
+
+    use crate::{
+        blocking::BlockingReceiver,
+        error::{RecvTimeoutError, TryRecvError},
+    };
+
+    impl<T> BlockingReceiver<T> for StdMpscReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            // https://doc.rust-lang.org/std/sync/mpsc/struct.RecvError.html
+            StdMpscReceiver::recv(self).ok()
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            StdMpscReceiver::recv_timeout(self, timeout).map_err(Into::into)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            StdMpscReceiver::try_recv(self).map_err(Into::into)
+        }
+    }
+}
+
+//
+mod introspect_impl {
+    use super::*;
+
+    use crate::introspect::Introspect;
+
+    // std's mpsc exposes none of these.
+    impl<T> Introspect for StdMpscReceiver<T> {}
+}
+
+//
+mod error_convert {
+    use super::*;
+
+    use crate::error::{RecvTimeoutError, TryRecvError};
+
+    impl From<TryRecvErrorInner> for TryRecvError {
+        fn from(err: TryRecvErrorInner) -> Self {
+            match err {
+                TryRecvErrorInner::Empty => Self::Empty,
+                TryRecvErrorInner::Disconnected => Self::Disconnected,
+            }
+        }
+    }
+
+    impl From<RecvTimeoutErrorInner> for RecvTimeoutError {
+        fn from(err: RecvTimeoutErrorInner) -> Self {
+            match err {
+                RecvTimeoutErrorInner::Timeout => Self::Timeout,
+                RecvTimeoutErrorInner::Disconnected => Self::Disconnected,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod blocking_impl_tests {
+    use core::time::Duration;
+    use std::time::Instant;
+
+    use crate::{
+        blocking::BlockingReceiver,
+        error::{RecvTimeoutError, TryRecvError},
+    };
+
+    #[test]
+    fn test_with_channel() {
+        {
+            let (tx, rx) = std::sync::mpsc::channel();
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert!(tx.send(1).is_ok());
+            assert!(tx.send(2).is_ok());
+            assert_eq!(receiver.recv(), Some(1));
+            assert_eq!(receiver.recv(), Some(2));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(
+                receiver.recv_deadline(Instant::now() + Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Disconnected)
+            );
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+
+    #[test]
+    fn test_with_sync_channel() {
+        {
+            let (tx, rx) = std::sync::mpsc::sync_channel(1);
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert!(tx.send(1).is_ok());
+            assert_eq!(receiver.recv(), Some(1));
+            assert!(tx.try_send(2).is_ok());
+            assert_eq!(receiver.recv(), Some(2));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(
+                receiver.recv_deadline(Instant::now() + Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Disconnected)
+            );
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod introspect_impl_tests {
+    use crate::blocking::BlockingReceiver;
+
+    #[test]
+    fn test_with_channel() {
+        {
+            let (_tx, rx) = std::sync::mpsc::channel::<usize>();
+            let receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert_eq!(receiver.len(), None);
+            assert_eq!(receiver.capacity(), None);
+            assert_eq!(receiver.is_closed(), None);
+            assert_eq!(receiver.sender_count(), None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod close_impl_tests {
+    use crate::blocking::BlockingReceiver;
+
+    #[test]
+    fn test_with_channel() {
+        {
+            let (tx, rx) = std::sync::mpsc::channel();
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert!(!receiver.close());
+            assert_eq!(tx.send(1), Ok(()));
+            assert_eq!(receiver.recv(), Some(1));
+        }
+    }
+}
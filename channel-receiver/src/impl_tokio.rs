@@ -2,15 +2,22 @@ use core::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
+use std::time::Instant;
 
 use tokio::sync::{
+    broadcast::error::{
+        RecvError as BroadcastRecvErrorInner, TryRecvError as BroadcastTryRecvErrorInner,
+    },
     mpsc::error::TryRecvError as MpscTryRecvErrorInner,
     oneshot::error::TryRecvError as OneshotTryRecvError,
 };
 pub use tokio::sync::{
+    broadcast::Receiver as TokioBroadcastReceiver,
     mpsc::{Receiver as TokioMpscReceiver, UnboundedReceiver as TokioMpscUnboundedReceiver},
     oneshot::Receiver as TokioOneshotReceiver,
+    watch::Receiver as TokioWatchReceiver,
 };
 
 //
@@ -20,34 +27,66 @@ pub struct TokioOneshotReceiverWrapper<T>(pub TokioOneshotReceiver<T>);
 mod single_consumer_impl {
     use super::*;
 
-    use crate::{error::TryRecvError, single_consumer::AsyncReceiver};
+    use crate::{error::TryRecvError, poll::PollReceiver, single_consumer::NativeAsyncReceiver};
 
-    #[async_trait::async_trait]
-    impl<T> AsyncReceiver<T> for TokioMpscReceiver<T> {
-        async fn recv(&mut self) -> Option<T>
+    impl<T> NativeAsyncReceiver<T> for TokioMpscReceiver<T> {
+        fn recv(&mut self) -> impl Future<Output = Option<T>> + Send + '_
         where
             T: Send,
         {
-            TokioMpscReceiver::recv(self).await
+            PollReceiver::recv(self)
         }
 
         fn try_recv(&mut self) -> Result<T, TryRecvError> {
             TokioMpscReceiver::try_recv(self).map_err(Into::into)
         }
+
+        fn close(&mut self) -> bool {
+            TokioMpscReceiver::close(self);
+            true
+        }
+
+        fn recv_many<'a>(
+            &'a mut self,
+            buf: &'a mut Vec<T>,
+            limit: usize,
+        ) -> impl Future<Output = usize> + Send + 'a
+        where
+            T: Send,
+            Self: Send,
+        {
+            TokioMpscReceiver::recv_many(self, buf, limit)
+        }
     }
 
-    #[async_trait::async_trait]
-    impl<T> AsyncReceiver<T> for TokioMpscUnboundedReceiver<T> {
-        async fn recv(&mut self) -> Option<T>
+    impl<T> NativeAsyncReceiver<T> for TokioMpscUnboundedReceiver<T> {
+        fn recv(&mut self) -> impl Future<Output = Option<T>> + Send + '_
         where
             T: Send,
         {
-            TokioMpscUnboundedReceiver::recv(self).await
+            PollReceiver::recv(self)
         }
 
         fn try_recv(&mut self) -> Result<T, TryRecvError> {
             TokioMpscUnboundedReceiver::try_recv(self).map_err(Into::into)
         }
+
+        fn close(&mut self) -> bool {
+            TokioMpscUnboundedReceiver::close(self);
+            true
+        }
+
+        fn recv_many<'a>(
+            &'a mut self,
+            buf: &'a mut Vec<T>,
+            limit: usize,
+        ) -> impl Future<Output = usize> + Send + 'a
+        where
+            T: Send,
+            Self: Send,
+        {
+            TokioMpscUnboundedReceiver::recv_many(self, buf, limit)
+        }
     }
 }
 
@@ -55,34 +94,147 @@ mod single_consumer_impl {
 mod generic_impl {
     use super::*;
 
-    use crate::{error::TryRecvError, generic::AsyncReceiver};
+    use crate::{error::TryRecvError, generic::NativeAsyncReceiver, poll::PollReceiver};
 
-    #[async_trait::async_trait]
-    impl<T> AsyncReceiver<T> for TokioMpscReceiver<T> {
-        async fn recv(&mut self) -> Option<T>
+    impl<T> NativeAsyncReceiver<T> for TokioMpscReceiver<T> {
+        fn recv(&mut self) -> impl Future<Output = Option<T>> + Send + '_
         where
             T: Send,
         {
-            TokioMpscReceiver::recv(self).await
+            PollReceiver::recv(self)
         }
 
         fn try_recv(&mut self) -> Result<T, TryRecvError> {
             TokioMpscReceiver::try_recv(self).map_err(Into::into)
         }
+
+        fn close(&mut self) -> bool {
+            TokioMpscReceiver::close(self);
+            true
+        }
+
+        fn recv_many<'a>(
+            &'a mut self,
+            buf: &'a mut Vec<T>,
+            limit: usize,
+        ) -> impl Future<Output = usize> + Send + 'a
+        where
+            T: Send,
+            Self: Send,
+        {
+            TokioMpscReceiver::recv_many(self, buf, limit)
+        }
     }
 
-    #[async_trait::async_trait]
-    impl<T> AsyncReceiver<T> for TokioMpscUnboundedReceiver<T> {
-        async fn recv(&mut self) -> Option<T>
+    impl<T> NativeAsyncReceiver<T> for TokioMpscUnboundedReceiver<T> {
+        fn recv(&mut self) -> impl Future<Output = Option<T>> + Send + '_
         where
             T: Send,
         {
-            TokioMpscUnboundedReceiver::recv(self).await
+            PollReceiver::recv(self)
         }
 
         fn try_recv(&mut self) -> Result<T, TryRecvError> {
             TokioMpscUnboundedReceiver::try_recv(self).map_err(Into::into)
         }
+
+        fn close(&mut self) -> bool {
+            TokioMpscUnboundedReceiver::close(self);
+            true
+        }
+
+        fn recv_many<'a>(
+            &'a mut self,
+            buf: &'a mut Vec<T>,
+            limit: usize,
+        ) -> impl Future<Output = usize> + Send + 'a
+        where
+            T: Send,
+            Self: Send,
+        {
+            TokioMpscUnboundedReceiver::recv_many(self, buf, limit)
+        }
+    }
+}
+
+//
+mod blocking_impl {
+    use super::*;
+
+    use crate::{
+        blocking::{block_on::recv_deadline, BlockingReceiver},
+        error::{RecvTimeoutError, TryRecvError},
+    };
+
+    impl<T> BlockingReceiver<T> for TokioMpscReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            TokioMpscReceiver::blocking_recv(self)
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            recv_deadline(
+                TokioMpscReceiver::recv(self),
+                Instant::now().checked_add(timeout),
+            )
+        }
+
+        fn recv_deadline(&mut self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+            recv_deadline(TokioMpscReceiver::recv(self), Some(deadline))
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            TokioMpscReceiver::try_recv(self).map_err(Into::into)
+        }
+
+        fn close(&mut self) -> bool {
+            TokioMpscReceiver::close(self);
+            true
+        }
+    }
+
+    impl<T> BlockingReceiver<T> for TokioMpscUnboundedReceiver<T> {
+        fn recv(&mut self) -> Option<T> {
+            TokioMpscUnboundedReceiver::blocking_recv(self)
+        }
+
+        fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+            recv_deadline(
+                TokioMpscUnboundedReceiver::recv(self),
+                Instant::now().checked_add(timeout),
+            )
+        }
+
+        fn recv_deadline(&mut self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+            recv_deadline(TokioMpscUnboundedReceiver::recv(self), Some(deadline))
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            TokioMpscUnboundedReceiver::try_recv(self).map_err(Into::into)
+        }
+
+        fn close(&mut self) -> bool {
+            TokioMpscUnboundedReceiver::close(self);
+            true
+        }
+    }
+}
+
+//
+mod poll_impl {
+    use super::*;
+
+    use crate::poll::PollReceiver;
+
+    impl<T> PollReceiver<T> for TokioMpscReceiver<T> {
+        fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+            TokioMpscReceiver::poll_recv(self, cx)
+        }
+    }
+
+    impl<T> PollReceiver<T> for TokioMpscUnboundedReceiver<T> {
+        fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+            TokioMpscUnboundedReceiver::poll_recv(self, cx)
+        }
     }
 }
 
@@ -111,11 +263,162 @@ mod one_shot_impl {
     }
 }
 
+//
+mod introspect_impl {
+    use super::*;
+
+    use core::any::Any;
+
+    use crate::introspect::{downcast, Introspect};
+
+    impl<T> Introspect for TokioMpscReceiver<T> {
+        fn len(&self) -> Option<usize> {
+            Some(TokioMpscReceiver::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(TokioMpscReceiver::is_empty(self))
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            Some(TokioMpscReceiver::capacity(self))
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            Some(TokioMpscReceiver::max_capacity(self))
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(TokioMpscReceiver::is_closed(self))
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(TokioMpscReceiver::sender_strong_count(self))
+        }
+    }
+
+    impl<T> Introspect for TokioMpscUnboundedReceiver<T> {
+        fn len(&self) -> Option<usize> {
+            Some(TokioMpscUnboundedReceiver::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(TokioMpscUnboundedReceiver::is_empty(self))
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(TokioMpscUnboundedReceiver::is_closed(self))
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(TokioMpscUnboundedReceiver::sender_strong_count(self))
+        }
+    }
+
+    impl<T> Introspect for TokioBroadcastReceiver<T> {
+        // Counts from where this receiver left off, so values it has lagged past are included.
+        fn len(&self) -> Option<usize> {
+            Some(TokioBroadcastReceiver::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(TokioBroadcastReceiver::is_empty(self))
+        }
+
+        fn as_any(&self) -> Option<&dyn Any>
+        where
+            Self: 'static,
+        {
+            Some(self)
+        }
+
+        fn same_channel(&self, other: &(dyn Introspect + 'static)) -> Option<bool>
+        where
+            Self: 'static,
+        {
+            Some(
+                downcast::<Self>(other)
+                    .is_some_and(|other| TokioBroadcastReceiver::same_channel(self, other)),
+            )
+        }
+    }
+
+    impl<T> Introspect for TokioWatchReceiver<T> {
+        fn as_any(&self) -> Option<&dyn Any>
+        where
+            Self: 'static,
+        {
+            Some(self)
+        }
+
+        fn same_channel(&self, other: &(dyn Introspect + 'static)) -> Option<bool>
+        where
+            Self: 'static,
+        {
+            Some(
+                downcast::<Self>(other)
+                    .is_some_and(|other| TokioWatchReceiver::same_channel(self, other)),
+            )
+        }
+    }
+}
+
+//
+mod latest_impl {
+    use super::*;
+
+    use crate::{
+        error::RecvError,
+        latest::{LatestReceiver, LatestRef},
+    };
+
+    #[async_trait::async_trait]
+    impl<T: Send + Sync> LatestReceiver<T> for TokioWatchReceiver<T> {
+        fn borrow(&self) -> LatestRef<'_, T> {
+            Box::new(TokioWatchReceiver::borrow(self))
+        }
+
+        async fn changed(&mut self) -> Result<(), RecvError> {
+            TokioWatchReceiver::changed(self)
+                .await
+                .map_err(|_| RecvError::Closed)
+        }
+
+        fn mark_unchanged(&mut self) {
+            TokioWatchReceiver::mark_unchanged(self)
+        }
+    }
+}
+
+//
+mod broadcast_impl {
+    use super::*;
+
+    use crate::{
+        broadcast::BroadcastReceiver,
+        error::{RecvError, TryRecvError},
+    };
+
+    #[async_trait::async_trait]
+    impl<T: Clone + Send> BroadcastReceiver<T> for TokioBroadcastReceiver<T> {
+        async fn recv(&mut self) -> Result<T, RecvError>
+        where
+            T: Send,
+        {
+            TokioBroadcastReceiver::recv(self).await.map_err(Into::into)
+        }
+
+        fn try_recv(&mut self) -> Result<T, TryRecvError> {
+            TokioBroadcastReceiver::try_recv(self).map_err(Into::into)
+        }
+    }
+}
+
 //
 mod error_convert {
     use super::*;
 
-    use crate::error::TryRecvError;
+    use crate::error::{RecvError, TryRecvError};
 
     impl From<MpscTryRecvErrorInner> for TryRecvError {
         fn from(err: MpscTryRecvErrorInner) -> Self {
@@ -134,6 +437,25 @@ mod error_convert {
             }
         }
     }
+
+    impl From<BroadcastTryRecvErrorInner> for TryRecvError {
+        fn from(err: BroadcastTryRecvErrorInner) -> Self {
+            match err {
+                BroadcastTryRecvErrorInner::Empty => Self::Empty,
+                BroadcastTryRecvErrorInner::Closed => Self::Closed,
+                BroadcastTryRecvErrorInner::Lagged(n) => Self::Lagged(n),
+            }
+        }
+    }
+
+    impl From<BroadcastRecvErrorInner> for RecvError {
+        fn from(err: BroadcastRecvErrorInner) -> Self {
+            match err {
+                BroadcastRecvErrorInner::Closed => Self::Closed,
+                BroadcastRecvErrorInner::Lagged(n) => Self::Lagged(n),
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -165,6 +487,75 @@ mod single_consumer_impl_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_recv_many_with_channel() {
+        {
+            let (tx, rx) = tokio::sync::mpsc::channel(4);
+            let mut receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            let mut buf = vec![];
+            assert_eq!(receiver.recv_many(&mut buf, 0).await, 0);
+            assert_eq!(
+                receiver.try_recv_many(&mut buf, 2),
+                Err(TryRecvError::Empty)
+            );
+            for i in 1..=4 {
+                assert!(tx.send(i).await.is_ok());
+            }
+            assert_eq!(receiver.recv_many(&mut buf, 3).await, 3);
+            assert_eq!(buf, vec![1, 2, 3]);
+            assert_eq!(receiver.recv_many(&mut buf, 3).await, 1);
+            assert_eq!(buf, vec![1, 2, 3, 4]);
+            let handle = tokio::spawn(async move {
+                tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+                assert!(tx.send(5).await.is_ok());
+                tx
+            });
+            buf.clear();
+            assert_eq!(receiver.recv_many(&mut buf, 3).await, 1);
+            assert_eq!(buf, vec![5]);
+            let tx = handle.await.unwrap();
+            assert!(tx.send(6).await.is_ok());
+            assert!(tx.send(7).await.is_ok());
+            drop(tx);
+            assert_eq!(receiver.try_recv_many(&mut buf, 1), Ok(1));
+            assert_eq!(receiver.try_recv_many(&mut buf, 3), Ok(1));
+            assert_eq!(buf, vec![5, 6, 7]);
+            assert_eq!(
+                receiver.try_recv_many(&mut buf, 3),
+                Err(TryRecvError::Disconnected)
+            );
+            assert_eq!(receiver.recv_many(&mut buf, 3).await, 0);
+        }
+    }
+
+    #[cfg(feature = "timer_tokio")]
+    #[tokio::test]
+    async fn test_recv_timeout_with_channel() {
+        use crate::{error::RecvTimeoutError, timer::TokioTimer};
+
+        {
+            let (tx, rx) = tokio::sync::mpsc::channel(1);
+            let mut receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            let timeout = tokio::time::Duration::from_millis(100);
+            assert_eq!(
+                receiver.recv_timeout(timeout, &TokioTimer).await,
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert!(tx.send(1).await.is_ok());
+            assert_eq!(receiver.recv_timeout(timeout, &TokioTimer).await, Ok(1));
+            let deadline = std::time::Instant::now() + timeout;
+            assert_eq!(
+                receiver.recv_deadline(deadline, &TokioTimer).await,
+                Err(RecvTimeoutError::Timeout)
+            );
+            drop(tx);
+            assert_eq!(
+                receiver.recv_timeout(timeout, &TokioTimer).await,
+                Err(RecvTimeoutError::Closed)
+            );
+        }
+    }
+
     #[tokio::test]
     async fn test_with_unbounded_channel() {
         {
@@ -191,6 +582,42 @@ mod single_consumer_impl_tests {
     }
 }
 
+#[cfg(test)]
+mod single_consumer_native_impl_tests {
+    use crate::{error::TryRecvError, single_consumer::NativeAsyncReceiver};
+
+    async fn recv_all<R: NativeAsyncReceiver<usize>>(receiver: &mut R) -> Vec<usize> {
+        let mut list = vec![];
+        while let Some(v) = receiver.recv().await {
+            list.push(v);
+        }
+        list
+    }
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+            assert!(tx.send(1).await.is_ok());
+            assert!(tx.send(2).await.is_ok());
+            drop(tx);
+            assert_eq!(recv_all(&mut rx).await, vec![1, 2]);
+            assert_eq!(
+                NativeAsyncReceiver::try_recv(&mut rx),
+                Err(TryRecvError::Disconnected)
+            );
+        }
+        {
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            let mut receiver: Box<dyn crate::single_consumer::AsyncReceiver<usize> + Send> =
+                Box::new(rx);
+            assert!(tx.send(1).is_ok());
+            drop(tx);
+            assert_eq!(recv_all(&mut receiver).await, vec![1]);
+        }
+    }
+}
+
 #[cfg(test)]
 mod generic_impl_tests {
     use crate::{error::TryRecvError, generic::AsyncReceiver};
@@ -246,6 +673,134 @@ mod generic_impl_tests {
     }
 }
 
+#[cfg(test)]
+mod blocking_impl_tests {
+    use core::time::Duration;
+    use std::time::Instant;
+
+    use crate::{
+        blocking::BlockingReceiver,
+        error::{RecvTimeoutError, TryRecvError},
+    };
+
+    #[test]
+    fn test_with_channel() {
+        {
+            let (tx, rx) = tokio::sync::mpsc::channel(1);
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert!(tx.blocking_send(1).is_ok());
+            assert_eq!(receiver.recv(), Some(1));
+            assert_eq!(tx.try_send(2), Ok(()));
+            assert_eq!(receiver.recv_timeout(Duration::from_millis(200)), Ok(2));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(
+                receiver.recv_deadline(Instant::now() + Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(100));
+                assert!(tx.blocking_send(3).is_ok());
+            });
+            assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(3));
+            handle.join().unwrap();
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Disconnected)
+            );
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+
+    #[test]
+    fn test_with_unbounded_channel() {
+        {
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert!(tx.send(1).is_ok());
+            assert!(tx.send(2).is_ok());
+            assert_eq!(receiver.recv(), Some(1));
+            assert_eq!(receiver.recv_timeout(Duration::from_millis(200)), Ok(2));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(
+                receiver.recv_deadline(Instant::now() + Duration::from_millis(200)),
+                Err(RecvTimeoutError::Timeout)
+            );
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+            drop(tx);
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+            assert_eq!(
+                receiver.recv_timeout(Duration::from_millis(200)),
+                Err(RecvTimeoutError::Disconnected)
+            );
+            assert_eq!(receiver.recv(), None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod poll_impl_tests {
+    use core::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    use futures_util::{future, Stream, StreamExt as _};
+
+    use crate::poll::PollReceiver;
+
+    struct Numbers<R>(R);
+
+    impl<R: PollReceiver<usize> + Unpin> Stream for Numbers<R> {
+        type Item = usize;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.get_mut().0.poll_recv(cx)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, rx) = tokio::sync::mpsc::channel(1);
+            let mut receiver: Box<dyn PollReceiver<usize> + Send> = Box::new(rx);
+            assert!(future::poll_fn(|cx| Poll::Ready(receiver.poll_recv(cx).is_pending())).await);
+            assert!(tx.send(1).await.is_ok());
+            assert_eq!(receiver.recv().await, Some(1));
+            let handle = tokio::spawn(async move {
+                for i in 2..=3 {
+                    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+                    assert!(tx.send(i).await.is_ok());
+                }
+            });
+            assert_eq!(Numbers(&mut receiver).collect::<Vec<_>>().await, vec![2, 3]);
+            handle.await.unwrap();
+            assert_eq!(receiver.recv().await, None);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded_channel() {
+        {
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            let mut stream = Numbers(rx);
+            assert!(tx.send(1).is_ok());
+            assert!(tx.send(2).is_ok());
+            assert_eq!(stream.next().await, Some(1));
+            assert_eq!(stream.0.recv().await, Some(2));
+            drop(tx);
+            assert_eq!(stream.next().await, None);
+        }
+    }
+}
+
 #[cfg(test)]
 mod one_shot_impl_tests {
     use super::*;
@@ -276,3 +831,148 @@ mod one_shot_impl_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod introspect_impl_tests {
+    use crate::single_consumer::AsyncReceiver;
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, rx) = tokio::sync::mpsc::channel(2);
+            let mut receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            assert_eq!(receiver.is_empty(), Some(true));
+            assert!(tx.send(1).await.is_ok());
+            assert_eq!(receiver.len(), Some(1));
+            assert_eq!(receiver.capacity(), Some(1));
+            assert_eq!(receiver.max_capacity(), Some(2));
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(receiver.is_closed(), Some(false));
+            drop(tx);
+            assert_eq!(receiver.is_closed(), Some(true));
+        }
+        {
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            let receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            assert!(tx.send(1).is_ok());
+            assert_eq!(receiver.len(), Some(1));
+            assert_eq!(receiver.max_capacity(), None);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_counts_with_channel() {
+        {
+            let (tx, rx) = tokio::sync::mpsc::channel::<usize>(1);
+            let receiver: Box<dyn AsyncReceiver<usize> + Send> = Box::new(rx);
+            let cloned = tx.clone();
+            assert_eq!(receiver.sender_count(), Some(2));
+            drop(tx);
+            drop(cloned);
+            assert_eq!(receiver.sender_count(), Some(0));
+            assert_eq!(receiver.receiver_count(), None);
+            assert_eq!(receiver.same_channel(&receiver), None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod close_impl_tests {
+    use crate::{blocking::BlockingReceiver, error::TryRecvError, single_consumer::AsyncReceiver};
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, rx) = tokio::sync::mpsc::channel(2);
+            let mut receiver: Box<dyn AsyncReceiver<usize>> = Box::new(rx);
+            assert!(tx.send(1).await.is_ok());
+            assert!(receiver.close());
+            assert!(tx.send(2).await.is_err());
+            assert_eq!(receiver.recv().await, Some(1));
+            assert_eq!(receiver.recv().await, None);
+        }
+        {
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            let mut receiver: Box<dyn BlockingReceiver<usize>> = Box::new(rx);
+            assert!(tx.send(1).is_ok());
+            assert!(receiver.close());
+            assert!(tx.send(2).is_err());
+            assert_eq!(receiver.try_recv(), Ok(1));
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+        }
+    }
+}
+
+#[cfg(test)]
+mod broadcast_impl_tests {
+    use crate::{
+        broadcast::BroadcastReceiver,
+        error::{RecvError, TryRecvError},
+    };
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, rx) = tokio::sync::broadcast::channel(2);
+            let mut receiver: Box<dyn BroadcastReceiver<usize> + Send> = Box::new(rx);
+            let mut other: Box<dyn BroadcastReceiver<usize> + Send> = Box::new(tx.subscribe());
+            assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+
+            assert_eq!(tx.send(1).ok(), Some(2));
+            assert_eq!(receiver.recv().await, Ok(1));
+            assert_eq!(other.try_recv(), Ok(1));
+
+            assert_eq!(tx.send(2).ok(), Some(2));
+            assert_eq!(tx.send(3).ok(), Some(2));
+            assert_eq!(tx.send(4).ok(), Some(2));
+            assert_eq!(receiver.len(), Some(3));
+            assert_eq!(receiver.recv().await, Err(RecvError::Lagged(1)));
+            assert_eq!(receiver.recv().await, Ok(3));
+            assert_eq!(other.try_recv(), Err(TryRecvError::Lagged(1)));
+            assert_eq!(other.try_recv(), Ok(3));
+            assert_eq!(receiver.same_channel(&other), Some(true));
+
+            drop(tx);
+            assert_eq!(receiver.recv().await, Ok(4));
+            assert_eq!(receiver.recv().await, Err(RecvError::Closed));
+            assert_eq!(other.try_recv(), Ok(4));
+            assert_eq!(other.try_recv(), Err(TryRecvError::Closed));
+        }
+    }
+}
+
+#[cfg(test)]
+mod latest_impl_tests {
+    use crate::{error::RecvError, latest::LatestReceiver};
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, rx) = tokio::sync::watch::channel(0);
+            let mut receiver: Box<dyn LatestReceiver<usize> + Send + Sync> = Box::new(rx);
+            assert_eq!(**receiver.borrow(), 0);
+
+            assert!(tx.send(1).is_ok());
+            assert!(tx.send(2).is_ok());
+            assert_eq!(receiver.changed().await, Ok(()));
+            assert_eq!(**receiver.borrow(), 2);
+            assert!(tokio::time::timeout(
+                tokio::time::Duration::from_millis(100),
+                receiver.changed()
+            )
+            .await
+            .is_err());
+
+            let mut cloned = receiver.clone();
+            assert!(tx.send(3).is_ok());
+            receiver.mark_unchanged();
+            assert_eq!(cloned.changed().await, Ok(()));
+            assert_eq!(**cloned.borrow(), 3);
+            assert_eq!(receiver.same_channel(&cloned), Some(true));
+
+            drop(tx);
+            assert_eq!(receiver.changed().await, Err(RecvError::Closed));
+            assert_eq!(**receiver.borrow(), 3);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use core::any::Any;
+
+//
+// Every method returns `None` when the backend can't tell.
+//
+// Like tokio, `capacity` is how many more messages fit right now, and `max_capacity` is the bound
+// the channel was created with. Both are `None` for unbounded channels.
+pub trait Introspect {
+    fn len(&self) -> Option<usize> {
+        None
+    }
+
+    fn is_empty(&self) -> Option<bool> {
+        self.len().map(|len| len == 0)
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn max_capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn is_closed(&self) -> Option<bool> {
+        None
+    }
+
+    fn sender_count(&self) -> Option<usize> {
+        None
+    }
+
+    fn receiver_count(&self) -> Option<usize> {
+        None
+    }
+
+    // The handle itself, so `same_channel` can get back to the backend type behind `other`.
+    fn as_any(&self) -> Option<&dyn Any>
+    where
+        Self: 'static,
+    {
+        None
+    }
+
+    // `Some(false)` whenever `other` is a different kind of handle.
+    fn same_channel(&self, _other: &(dyn Introspect + 'static)) -> Option<bool>
+    where
+        Self: 'static,
+    {
+        None
+    }
+}
+
+impl<I> Introspect for Box<I>
+where
+    I: Introspect + ?Sized,
+{
+    fn len(&self) -> Option<usize> {
+        (**self).len()
+    }
+
+    fn is_empty(&self) -> Option<bool> {
+        (**self).is_empty()
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        (**self).capacity()
+    }
+
+    fn max_capacity(&self) -> Option<usize> {
+        (**self).max_capacity()
+    }
+
+    fn is_closed(&self) -> Option<bool> {
+        (**self).is_closed()
+    }
+
+    fn sender_count(&self) -> Option<usize> {
+        (**self).sender_count()
+    }
+
+    fn receiver_count(&self) -> Option<usize> {
+        (**self).receiver_count()
+    }
+
+    fn as_any(&self) -> Option<&dyn Any>
+    where
+        Self: 'static,
+    {
+        (**self).as_any()
+    }
+
+    fn same_channel(&self, other: &(dyn Introspect + 'static)) -> Option<bool>
+    where
+        Self: 'static,
+    {
+        (**self).same_channel(other)
+    }
+}
+
+#[cfg(any(
+    feature = "impl_tokio",
+    feature = "impl_flume",
+    feature = "impl_crossbeam",
+    feature = "introspect_tokio",
+    feature = "introspect_flume",
+    feature = "introspect_crossbeam",
+    feature = "introspect_futures_channel"
+))]
+pub(crate) fn downcast<'a, I: 'static>(other: &'a (dyn Introspect + 'static)) -> Option<&'a I> {
+    other.as_any()?.downcast_ref::<I>()
+}
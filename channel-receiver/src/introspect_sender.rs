@@ -0,0 +1,419 @@
+//
+// `Introspect` for the backends' sender types. The impls live next to the trait so that
+// channel-sender can re-export it; each group is turned on by channel-sender's `impl_*` features.
+//
+#[cfg(feature = "introspect_tokio")]
+mod tokio_impl {
+    use core::any::Any;
+
+    use tokio::sync::{
+        broadcast::Sender as TokioBroadcastSender,
+        mpsc::{Sender as TokioMpscSender, UnboundedSender as TokioMpscUnboundedSender},
+        watch::Sender as TokioWatchSender,
+    };
+
+    use crate::introspect::{downcast, Introspect};
+
+    impl<T> Introspect for TokioMpscSender<T> {
+        // Outstanding permits count towards the length as well.
+        fn len(&self) -> Option<usize> {
+            Some(TokioMpscSender::max_capacity(self) - TokioMpscSender::capacity(self))
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            Some(TokioMpscSender::capacity(self))
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            Some(TokioMpscSender::max_capacity(self))
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(TokioMpscSender::is_closed(self))
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(TokioMpscSender::strong_count(self))
+        }
+
+        fn as_any(&self) -> Option<&dyn Any>
+        where
+            Self: 'static,
+        {
+            Some(self)
+        }
+
+        fn same_channel(&self, other: &(dyn Introspect + 'static)) -> Option<bool>
+        where
+            Self: 'static,
+        {
+            Some(
+                downcast::<Self>(other)
+                    .is_some_and(|other| TokioMpscSender::same_channel(self, other)),
+            )
+        }
+    }
+
+    impl<T> Introspect for TokioMpscUnboundedSender<T> {
+        fn is_closed(&self) -> Option<bool> {
+            Some(TokioMpscUnboundedSender::is_closed(self))
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(TokioMpscUnboundedSender::strong_count(self))
+        }
+
+        fn as_any(&self) -> Option<&dyn Any>
+        where
+            Self: 'static,
+        {
+            Some(self)
+        }
+
+        fn same_channel(&self, other: &(dyn Introspect + 'static)) -> Option<bool>
+        where
+            Self: 'static,
+        {
+            Some(
+                downcast::<Self>(other)
+                    .is_some_and(|other| TokioMpscUnboundedSender::same_channel(self, other)),
+            )
+        }
+    }
+
+    impl<T> Introspect for TokioBroadcastSender<T> {
+        // Values still waiting on the slowest receiver.
+        fn len(&self) -> Option<usize> {
+            Some(TokioBroadcastSender::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(TokioBroadcastSender::is_empty(self))
+        }
+
+        fn receiver_count(&self) -> Option<usize> {
+            Some(TokioBroadcastSender::receiver_count(self))
+        }
+
+        fn as_any(&self) -> Option<&dyn Any>
+        where
+            Self: 'static,
+        {
+            Some(self)
+        }
+
+        fn same_channel(&self, other: &(dyn Introspect + 'static)) -> Option<bool>
+        where
+            Self: 'static,
+        {
+            Some(
+                downcast::<Self>(other)
+                    .is_some_and(|other| TokioBroadcastSender::same_channel(self, other)),
+            )
+        }
+    }
+
+    impl<T> Introspect for TokioWatchSender<T> {
+        fn is_closed(&self) -> Option<bool> {
+            Some(TokioWatchSender::is_closed(self))
+        }
+
+        fn receiver_count(&self) -> Option<usize> {
+            Some(TokioWatchSender::receiver_count(self))
+        }
+    }
+}
+
+#[cfg(feature = "introspect_async_channel")]
+mod async_channel_impl {
+    use async_channel::Sender as AsyncChannelSender;
+
+    use crate::introspect::Introspect;
+
+    impl<T> Introspect for AsyncChannelSender<T> {
+        fn len(&self) -> Option<usize> {
+            Some(AsyncChannelSender::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(AsyncChannelSender::is_empty(self))
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            AsyncChannelSender::capacity(self).map(|cap| cap.saturating_sub(self.len()))
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            AsyncChannelSender::capacity(self)
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(AsyncChannelSender::is_closed(self))
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(AsyncChannelSender::sender_count(self))
+        }
+
+        fn receiver_count(&self) -> Option<usize> {
+            Some(AsyncChannelSender::receiver_count(self))
+        }
+    }
+}
+
+#[cfg(feature = "introspect_async_broadcast")]
+mod async_broadcast_impl {
+    use async_broadcast::Sender as AsyncBroadcastSender;
+
+    use crate::introspect::Introspect;
+
+    impl<T> Introspect for AsyncBroadcastSender<T> {
+        fn len(&self) -> Option<usize> {
+            Some(AsyncBroadcastSender::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(AsyncBroadcastSender::is_empty(self))
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            Some(AsyncBroadcastSender::capacity(self).saturating_sub(self.len()))
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            Some(AsyncBroadcastSender::capacity(self))
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(AsyncBroadcastSender::is_closed(self))
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(AsyncBroadcastSender::sender_count(self))
+        }
+
+        // Counts only active receivers, inactive ones don't get the values.
+        fn receiver_count(&self) -> Option<usize> {
+            Some(AsyncBroadcastSender::receiver_count(self))
+        }
+    }
+}
+
+#[cfg(feature = "introspect_flume")]
+mod flume_impl {
+    use core::any::Any;
+
+    use flume::Sender as FlumeSender;
+
+    use crate::introspect::{downcast, Introspect};
+
+    impl<T> Introspect for FlumeSender<T> {
+        fn len(&self) -> Option<usize> {
+            Some(FlumeSender::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(FlumeSender::is_empty(self))
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            FlumeSender::capacity(self).map(|cap| cap.saturating_sub(self.len()))
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            FlumeSender::capacity(self)
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(FlumeSender::is_disconnected(self))
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(FlumeSender::sender_count(self))
+        }
+
+        fn receiver_count(&self) -> Option<usize> {
+            Some(FlumeSender::receiver_count(self))
+        }
+
+        fn as_any(&self) -> Option<&dyn Any>
+        where
+            Self: 'static,
+        {
+            Some(self)
+        }
+
+        fn same_channel(&self, other: &(dyn Introspect + 'static)) -> Option<bool>
+        where
+            Self: 'static,
+        {
+            Some(
+                downcast::<Self>(other).is_some_and(|other| FlumeSender::same_channel(self, other)),
+            )
+        }
+    }
+}
+
+#[cfg(feature = "introspect_crossbeam")]
+mod crossbeam_impl {
+    use core::any::Any;
+
+    use crossbeam_channel::Sender as CrossbeamSender;
+
+    use crate::introspect::{downcast, Introspect};
+
+    // crossbeam-channel has no way to check for a disconnect without sending.
+    impl<T> Introspect for CrossbeamSender<T> {
+        fn len(&self) -> Option<usize> {
+            Some(CrossbeamSender::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(CrossbeamSender::is_empty(self))
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            CrossbeamSender::capacity(self).map(|cap| cap.saturating_sub(self.len()))
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            CrossbeamSender::capacity(self)
+        }
+
+        fn as_any(&self) -> Option<&dyn Any>
+        where
+            Self: 'static,
+        {
+            Some(self)
+        }
+
+        fn same_channel(&self, other: &(dyn Introspect + 'static)) -> Option<bool>
+        where
+            Self: 'static,
+        {
+            Some(
+                downcast::<Self>(other)
+                    .is_some_and(|other| CrossbeamSender::same_channel(self, other)),
+            )
+        }
+    }
+}
+
+#[cfg(feature = "introspect_futures_channel")]
+mod futures_channel_impl {
+    use core::any::Any;
+
+    use futures_channel::mpsc::UnboundedSender as FuturesMpscUnboundedSender;
+
+    use crate::introspect::{downcast, Introspect};
+
+    impl<T> Introspect for FuturesMpscUnboundedSender<T> {
+        fn len(&self) -> Option<usize> {
+            Some(FuturesMpscUnboundedSender::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(FuturesMpscUnboundedSender::is_empty(self))
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(FuturesMpscUnboundedSender::is_closed(self))
+        }
+
+        fn as_any(&self) -> Option<&dyn Any>
+        where
+            Self: 'static,
+        {
+            Some(self)
+        }
+
+        fn same_channel(&self, other: &(dyn Introspect + 'static)) -> Option<bool>
+        where
+            Self: 'static,
+        {
+            Some(
+                downcast::<Self>(other)
+                    .is_some_and(|other| FuturesMpscUnboundedSender::same_receiver(self, other)),
+            )
+        }
+    }
+}
+
+#[cfg(feature = "introspect_kanal")]
+mod kanal_impl {
+    use kanal::{AsyncSender as KanalAsyncSender, Sender as KanalSender};
+
+    use crate::introspect::Introspect;
+
+    impl<T> Introspect for KanalSender<T> {
+        fn len(&self) -> Option<usize> {
+            Some(KanalSender::len(self))
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Some(KanalSender::is_empty(self))
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            self.max_capacity()
+                .map(|cap| cap.saturating_sub(KanalSender::len(self)))
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            KanalSender::is_bounded(self).then(|| KanalSender::capacity(self))
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(KanalSender::is_disconnected(self))
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(KanalSender::sender_count(self) as usize)
+        }
+
+        fn receiver_count(&self) -> Option<usize> {
+            Some(KanalSender::receiver_count(self) as usize)
+        }
+    }
+
+    impl<T> Introspect for KanalAsyncSender<T> {
+        fn len(&self) -> Option<usize> {
+            Introspect::len(self.as_sync())
+        }
+
+        fn is_empty(&self) -> Option<bool> {
+            Introspect::is_empty(self.as_sync())
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            Introspect::capacity(self.as_sync())
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            Introspect::max_capacity(self.as_sync())
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Introspect::is_closed(self.as_sync())
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Introspect::sender_count(self.as_sync())
+        }
+
+        fn receiver_count(&self) -> Option<usize> {
+            Introspect::receiver_count(self.as_sync())
+        }
+    }
+}
+
+mod std_impl {
+    use std::sync::mpsc::{Sender as StdMpscSender, SyncSender as StdMpscSyncSender};
+
+    use crate::introspect::Introspect;
+
+    // std's mpsc exposes none of these.
+    impl<T> Introspect for StdMpscSender<T> {}
+
+    impl<T> Introspect for StdMpscSyncSender<T> {}
+}
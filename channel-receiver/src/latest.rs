@@ -0,0 +1,22 @@
+use core::ops::Deref;
+
+use dyn_clone::{clone_trait_object, DynClone};
+
+use crate::{error::RecvError, introspect::Introspect};
+
+//
+// Only the most recent value is kept, so a receiver that was busy only sees the last one.
+#[async_trait::async_trait]
+pub trait LatestReceiver<T>: DynClone + Introspect {
+    // The guard may hold a lock on the value, so drop it before awaiting.
+    fn borrow(&self) -> LatestRef<'_, T>;
+
+    // Waits for a value this receiver hasn't seen yet and marks it seen, fails once the sender
+    // is gone.
+    async fn changed(&mut self) -> Result<(), RecvError>;
+
+    fn mark_unchanged(&mut self);
+}
+clone_trait_object!(<T> LatestReceiver<T>);
+
+pub type LatestRef<'a, T> = Box<dyn Deref<Target = T> + 'a>;
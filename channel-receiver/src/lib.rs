@@ -10,11 +10,46 @@ pub use one_shot as oneshot;
 
 pub mod generic;
 
+pub mod blocking;
+
+pub mod broadcast;
+
+pub mod latest;
+
+pub mod poll;
+pub use poll::PollReceiver;
+
+pub mod introspect;
+pub use introspect::Introspect;
+mod introspect_sender;
+
+pub mod timer;
+pub use timer::Timer;
+
+pub mod stream;
+pub use stream::ReceiverStream;
+
 pub mod error;
-pub use error::TryRecvError;
+pub use error::{RecvError, RecvTimeoutError, TryRecvError};
 
 //
+#[cfg(feature = "impl_async_broadcast")]
+pub mod impl_async_broadcast;
 #[cfg(feature = "impl_async_channel")]
 pub mod impl_async_channel;
+#[cfg(feature = "impl_crossbeam")]
+pub mod impl_crossbeam;
+#[cfg(feature = "impl_flume")]
+pub mod impl_flume;
+#[cfg(feature = "impl_futures_channel")]
+pub mod impl_futures_channel;
+#[cfg(feature = "impl_kanal")]
+pub mod impl_kanal;
 #[cfg(feature = "impl_tokio")]
 pub mod impl_tokio;
+
+pub mod impl_std;
+
+//
+#[cfg(feature = "bridge")]
+pub mod bridge;
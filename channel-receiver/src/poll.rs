@@ -0,0 +1,71 @@
+use core::{
+    fmt,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+//
+pub trait PollReceiver<T> {
+    fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>>;
+
+    // Backends with a `poll_recv` can forward `NativeAsyncReceiver::recv` here instead of
+    // wrapping their own future.
+    fn recv(&mut self) -> Recv<'_, Self, T>
+    where
+        Self: Sized,
+    {
+        Recv::new(self)
+    }
+}
+
+impl<T, R> PollReceiver<T> for Box<R>
+where
+    R: PollReceiver<T> + ?Sized,
+{
+    fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        (**self).poll_recv(cx)
+    }
+}
+
+impl<T, R> PollReceiver<T> for &mut R
+where
+    R: PollReceiver<T> + ?Sized,
+{
+    fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        (**self).poll_recv(cx)
+    }
+}
+
+//
+pub struct Recv<'a, R: ?Sized, T> {
+    receiver: &'a mut R,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, R: ?Sized, T> Recv<'a, R, T> {
+    pub fn new(receiver: &'a mut R) -> Self {
+        Self {
+            receiver,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<R: ?Sized, T> fmt::Debug for Recv<'_, R, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recv").finish_non_exhaustive()
+    }
+}
+
+impl<R, T> Future for Recv<'_, R, T>
+where
+    R: PollReceiver<T> + ?Sized,
+{
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().receiver.poll_recv(cx)
+    }
+}
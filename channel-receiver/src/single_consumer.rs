@@ -1,11 +1,284 @@
-use crate::error::TryRecvError;
+use core::{future::Future, time::Duration};
+use std::time::Instant;
+
+use crate::{
+    error::{RecvTimeoutError, TryRecvError},
+    introspect::Introspect,
+    timer::{timeout_at, Timer},
+};
 
 //
 #[async_trait::async_trait]
-pub trait AsyncReceiver<T> {
+pub trait AsyncReceiver<T>: Introspect + Send {
     async fn recv(&mut self) -> Option<T>
     where
         T: Send;
 
     fn try_recv(&mut self) -> Result<T, TryRecvError>;
+
+    fn close(&mut self) -> bool {
+        false
+    }
+
+    async fn recv_many(&mut self, buf: &mut Vec<T>, limit: usize) -> usize
+    where
+        T: Send,
+    {
+        if limit == 0 {
+            return 0;
+        }
+        match self.recv().await {
+            Some(t) => buf.push(t),
+            None => return 0,
+        }
+        1 + self.try_recv_many(buf, limit - 1).unwrap_or(0)
+    }
+
+    fn try_recv_many(&mut self, buf: &mut Vec<T>, limit: usize) -> Result<usize, TryRecvError> {
+        let mut n = 0;
+        while n < limit {
+            match self.try_recv() {
+                Ok(t) => {
+                    buf.push(t);
+                    n += 1;
+                }
+                Err(err) if n == 0 => return Err(err),
+                Err(_) => break,
+            }
+        }
+        Ok(n)
+    }
+
+    async fn recv_timeout(
+        &mut self,
+        timeout: Duration,
+        timer: &dyn Timer,
+    ) -> Result<T, RecvTimeoutError>
+    where
+        T: Send,
+    {
+        match timer.now().checked_add(timeout) {
+            Some(deadline) => self.recv_deadline(deadline, timer).await,
+            None => self.recv().await.ok_or(RecvTimeoutError::Closed),
+        }
+    }
+
+    async fn recv_deadline(
+        &mut self,
+        deadline: Instant,
+        timer: &dyn Timer,
+    ) -> Result<T, RecvTimeoutError>
+    where
+        T: Send,
+    {
+        match timeout_at(self.recv(), deadline, timer).await {
+            Some(Some(t)) => Ok(t),
+            Some(None) => Err(RecvTimeoutError::Closed),
+            None => Err(RecvTimeoutError::Timeout),
+        }
+    }
+}
+
+//
+pub trait NativeAsyncReceiver<T>: Introspect {
+    fn recv(&mut self) -> impl Future<Output = Option<T>> + Send + '_
+    where
+        T: Send;
+
+    fn try_recv(&mut self) -> Result<T, TryRecvError>;
+
+    fn close(&mut self) -> bool {
+        false
+    }
+
+    fn recv_many<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<T>,
+        limit: usize,
+    ) -> impl Future<Output = usize> + Send + 'a
+    where
+        T: Send,
+        Self: Send,
+    {
+        async move {
+            if limit == 0 {
+                return 0;
+            }
+            match NativeAsyncReceiver::recv(&mut *self).await {
+                Some(t) => buf.push(t),
+                None => return 0,
+            }
+            1 + NativeAsyncReceiver::try_recv_many(self, buf, limit - 1).unwrap_or(0)
+        }
+    }
+
+    fn try_recv_many(&mut self, buf: &mut Vec<T>, limit: usize) -> Result<usize, TryRecvError> {
+        let mut n = 0;
+        while n < limit {
+            match NativeAsyncReceiver::try_recv(self) {
+                Ok(t) => {
+                    buf.push(t);
+                    n += 1;
+                }
+                Err(err) if n == 0 => return Err(err),
+                Err(_) => break,
+            }
+        }
+        Ok(n)
+    }
+
+    fn recv_timeout<'a>(
+        &'a mut self,
+        timeout: Duration,
+        timer: &'a dyn Timer,
+    ) -> impl Future<Output = Result<T, RecvTimeoutError>> + Send + 'a
+    where
+        T: Send,
+        Self: Send,
+    {
+        async move {
+            match timer.now().checked_add(timeout) {
+                Some(deadline) => NativeAsyncReceiver::recv_deadline(self, deadline, timer).await,
+                None => NativeAsyncReceiver::recv(self)
+                    .await
+                    .ok_or(RecvTimeoutError::Closed),
+            }
+        }
+    }
+
+    fn recv_deadline<'a>(
+        &'a mut self,
+        deadline: Instant,
+        timer: &'a dyn Timer,
+    ) -> impl Future<Output = Result<T, RecvTimeoutError>> + Send + 'a
+    where
+        T: Send,
+        Self: Send,
+    {
+        async move {
+            match timeout_at(NativeAsyncReceiver::recv(self), deadline, timer).await {
+                Some(Some(t)) => Ok(t),
+                Some(None) => Err(RecvTimeoutError::Closed),
+                None => Err(RecvTimeoutError::Timeout),
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, R> AsyncReceiver<T> for R
+where
+    R: NativeAsyncReceiver<T> + Send,
+{
+    async fn recv(&mut self) -> Option<T>
+    where
+        T: Send,
+    {
+        NativeAsyncReceiver::recv(self).await
+    }
+
+    fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        NativeAsyncReceiver::try_recv(self)
+    }
+
+    fn close(&mut self) -> bool {
+        NativeAsyncReceiver::close(self)
+    }
+
+    async fn recv_many(&mut self, buf: &mut Vec<T>, limit: usize) -> usize
+    where
+        T: Send,
+    {
+        NativeAsyncReceiver::recv_many(self, buf, limit).await
+    }
+
+    fn try_recv_many(&mut self, buf: &mut Vec<T>, limit: usize) -> Result<usize, TryRecvError> {
+        NativeAsyncReceiver::try_recv_many(self, buf, limit)
+    }
+
+    async fn recv_timeout(
+        &mut self,
+        timeout: Duration,
+        timer: &dyn Timer,
+    ) -> Result<T, RecvTimeoutError>
+    where
+        T: Send,
+    {
+        NativeAsyncReceiver::recv_timeout(self, timeout, timer).await
+    }
+
+    async fn recv_deadline(
+        &mut self,
+        deadline: Instant,
+        timer: &dyn Timer,
+    ) -> Result<T, RecvTimeoutError>
+    where
+        T: Send,
+    {
+        NativeAsyncReceiver::recv_deadline(self, deadline, timer).await
+    }
+}
+
+impl<T, R> NativeAsyncReceiver<T> for Box<R>
+where
+    R: AsyncReceiver<T> + ?Sized,
+{
+    #[allow(clippy::manual_async_fn)]
+    fn recv(&mut self) -> impl Future<Output = Option<T>> + Send + '_
+    where
+        T: Send,
+    {
+        async move { AsyncReceiver::recv(&mut **self).await }
+    }
+
+    fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        AsyncReceiver::try_recv(&mut **self)
+    }
+
+    fn close(&mut self) -> bool {
+        AsyncReceiver::close(&mut **self)
+    }
+
+    #[allow(clippy::manual_async_fn)]
+    fn recv_many<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<T>,
+        limit: usize,
+    ) -> impl Future<Output = usize> + Send + 'a
+    where
+        T: Send,
+        Self: Send,
+    {
+        async move { AsyncReceiver::recv_many(&mut **self, buf, limit).await }
+    }
+
+    fn try_recv_many(&mut self, buf: &mut Vec<T>, limit: usize) -> Result<usize, TryRecvError> {
+        AsyncReceiver::try_recv_many(&mut **self, buf, limit)
+    }
+
+    #[allow(clippy::manual_async_fn)]
+    fn recv_timeout<'a>(
+        &'a mut self,
+        timeout: Duration,
+        timer: &'a dyn Timer,
+    ) -> impl Future<Output = Result<T, RecvTimeoutError>> + Send + 'a
+    where
+        T: Send,
+        Self: Send,
+    {
+        async move { AsyncReceiver::recv_timeout(&mut **self, timeout, timer).await }
+    }
+
+    #[allow(clippy::manual_async_fn)]
+    fn recv_deadline<'a>(
+        &'a mut self,
+        deadline: Instant,
+        timer: &'a dyn Timer,
+    ) -> impl Future<Output = Result<T, RecvTimeoutError>> + Send + 'a
+    where
+        T: Send,
+        Self: Send,
+    {
+        async move { AsyncReceiver::recv_deadline(&mut **self, deadline, timer).await }
+    }
 }
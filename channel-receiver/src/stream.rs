@@ -0,0 +1,206 @@
+use core::{
+    fmt,
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::{stream::FusedStream, Stream};
+
+use crate::{generic, multi_consumer, single_consumer};
+
+//
+type RecvFuture<R, T> = Pin<Box<dyn Future<Output = (Option<T>, R)> + Send>>;
+
+enum State<R, T> {
+    Idle(R),
+    Receiving(RecvFuture<R, T>),
+    Terminated,
+}
+
+//
+pub struct ReceiverStream<R, T> {
+    state: State<R, T>,
+    make_recv_future: fn(R) -> RecvFuture<R, T>,
+}
+
+impl<R, T> fmt::Debug for ReceiverStream<R, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReceiverStream")
+            .field("is_terminated", &self.is_terminated())
+            .finish()
+    }
+}
+
+impl<R, T> Unpin for ReceiverStream<R, T> {}
+
+impl<R, T> ReceiverStream<R, T>
+where
+    R: Send + 'static,
+    T: Send + 'static,
+{
+    pub fn from_generic(receiver: R) -> Self
+    where
+        R: generic::NativeAsyncReceiver<T>,
+    {
+        Self::with_recv_future(receiver, |mut receiver| {
+            Box::pin(async move {
+                let t = generic::NativeAsyncReceiver::recv(&mut receiver).await;
+                (t, receiver)
+            })
+        })
+    }
+
+    pub fn from_single_consumer(receiver: R) -> Self
+    where
+        R: single_consumer::NativeAsyncReceiver<T>,
+    {
+        Self::with_recv_future(receiver, |mut receiver| {
+            Box::pin(async move {
+                let t = single_consumer::NativeAsyncReceiver::recv(&mut receiver).await;
+                (t, receiver)
+            })
+        })
+    }
+
+    pub fn from_multi_consumer(receiver: R) -> Self
+    where
+        R: multi_consumer::NativeAsyncReceiver<T>,
+    {
+        Self::with_recv_future(receiver, |mut receiver| {
+            Box::pin(async move {
+                let t = multi_consumer::NativeAsyncReceiver::recv(&mut receiver).await;
+                (t, receiver)
+            })
+        })
+    }
+
+    fn with_recv_future(receiver: R, make_recv_future: fn(R) -> RecvFuture<R, T>) -> Self {
+        Self {
+            state: State::Idle(receiver),
+            make_recv_future,
+        }
+    }
+}
+
+impl<R, T> Stream for ReceiverStream<R, T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            match mem::replace(&mut this.state, State::Terminated) {
+                State::Idle(receiver) => {
+                    this.state = State::Receiving((this.make_recv_future)(receiver));
+                }
+                State::Receiving(mut fut) => {
+                    return match fut.as_mut().poll(cx) {
+                        Poll::Ready((Some(t), receiver)) => {
+                            this.state = State::Idle(receiver);
+                            Poll::Ready(Some(t))
+                        }
+                        Poll::Ready((None, _)) => Poll::Ready(None),
+                        Poll::Pending => {
+                            this.state = State::Receiving(fut);
+                            Poll::Pending
+                        }
+                    };
+                }
+                State::Terminated => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl<R, T> FusedStream for ReceiverStream<R, T> {
+    fn is_terminated(&self) -> bool {
+        matches!(self.state, State::Terminated)
+    }
+}
+
+#[cfg(all(test, feature = "impl_tokio"))]
+mod tokio_tests {
+    use futures_util::StreamExt as _;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_from_single_consumer() {
+        {
+            let (tx, rx) = tokio::sync::mpsc::channel(1);
+            let receiver: Box<dyn single_consumer::AsyncReceiver<usize> + Send> = Box::new(rx);
+            let mut stream = ReceiverStream::from_single_consumer(receiver);
+            assert!(!stream.is_terminated());
+            let handle = tokio::spawn(async move {
+                for i in 1..=3 {
+                    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+                    assert!(tx.send(i).await.is_ok());
+                }
+            });
+            assert_eq!(stream.next().await, Some(1));
+            assert_eq!((&mut stream).collect::<Vec<_>>().await, vec![2, 3]);
+            assert!(stream.is_terminated());
+            assert_eq!(stream.next().await, None);
+            handle.await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_from_generic() {
+        {
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            let receiver: Box<dyn generic::AsyncReceiver<usize> + Send> = Box::new(rx);
+            let mut stream = ReceiverStream::from_generic(receiver);
+            assert!(tx.send(1).is_ok());
+            assert!(tx.send(2).is_ok());
+            assert_eq!(stream.next().await, Some(1));
+            assert_eq!(stream.next().await, Some(2));
+            assert!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), stream.next())
+                    .await
+                    .is_err()
+            );
+            assert!(tx.send(3).is_ok());
+            assert_eq!(stream.next().await, Some(3));
+            drop(tx);
+            assert_eq!(stream.next().await, None);
+            assert!(stream.is_terminated());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "impl_async_channel"))]
+mod async_channel_tests {
+    use futures_util::StreamExt as _;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_from_multi_consumer() {
+        {
+            let (tx, rx) = async_channel::bounded(1);
+            let receiver: Box<dyn multi_consumer::AsyncReceiver<usize> + Send> = Box::new(rx);
+            let mut stream = ReceiverStream::from_multi_consumer(receiver.clone());
+            let handle = tokio::spawn(async move {
+                for i in 1..=3 {
+                    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+                    assert_eq!(tx.send(i).await, Ok(()));
+                }
+            });
+            assert_eq!(stream.next().await, Some(1));
+            assert_eq!(stream.collect::<Vec<_>>().await, vec![2, 3]);
+            handle.await.unwrap();
+            drop(receiver);
+        }
+        {
+            let (tx, rx) = async_channel::unbounded();
+            let mut stream = ReceiverStream::from_generic(rx);
+            assert_eq!(tx.send(1).await, Ok(()));
+            assert_eq!(stream.next().await, Some(1));
+            drop(tx);
+            assert_eq!(stream.next().await, None);
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use core::{
+    fmt,
+    future::{poll_fn, Future},
+    pin::{pin, Pin},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+//
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+pub trait Timer: Send + Sync {
+    fn now(&self) -> Instant;
+
+    fn sleep_until(&self, deadline: Instant) -> Sleep;
+}
+
+//
+#[cfg(feature = "timer_tokio")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TokioTimer;
+
+#[cfg(feature = "timer_tokio")]
+impl Timer for TokioTimer {
+    fn now(&self) -> Instant {
+        // Follows the paused clock in tests that use `tokio::time::pause`.
+        tokio::time::Instant::now().into_std()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Sleep {
+        Box::pin(tokio::time::sleep_until(deadline.into()))
+    }
+}
+
+//
+#[cfg(feature = "timer_async_io")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AsyncIoTimer;
+
+#[cfg(feature = "timer_async_io")]
+impl Timer for AsyncIoTimer {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Sleep {
+        let timer = async_io::Timer::at(deadline);
+        Box::pin(async move {
+            timer.await;
+        })
+    }
+}
+
+//
+// A clock that only moves when told to, for tests.
+#[derive(Clone)]
+pub struct ManualTimer {
+    inner: Arc<Mutex<ManualTimerInner>>,
+}
+
+struct ManualTimerInner {
+    now: Instant,
+    next_id: u64,
+    // One slot per pending sleep, so polling a sleep again replaces its waker.
+    wakers: Vec<(u64, Waker)>,
+}
+
+impl fmt::Debug for ManualTimer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ManualTimer")
+            .field("now", &self.now())
+            .finish()
+    }
+}
+
+impl Default for ManualTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ManualTimer {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(ManualTimerInner {
+                now: Instant::now(),
+                next_id: 0,
+                wakers: vec![],
+            })),
+        }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        let wakers = {
+            let mut inner = self.inner.lock().expect("ManualTimer lock poisoned");
+            inner.now += duration;
+            core::mem::take(&mut inner.wakers)
+        };
+        wakers.into_iter().for_each(|(_, waker)| waker.wake());
+    }
+}
+
+impl Timer for ManualTimer {
+    fn now(&self) -> Instant {
+        self.inner.lock().expect("ManualTimer lock poisoned").now
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Sleep {
+        let id = {
+            let mut inner = self.inner.lock().expect("ManualTimer lock poisoned");
+            inner.next_id += 1;
+            inner.next_id
+        };
+        Box::pin(ManualSleep {
+            inner: self.inner.clone(),
+            id,
+            deadline,
+        })
+    }
+}
+
+struct ManualSleep {
+    inner: Arc<Mutex<ManualTimerInner>>,
+    id: u64,
+    deadline: Instant,
+}
+
+impl Future for ManualSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut inner = self.inner.lock().expect("ManualTimer lock poisoned");
+        if inner.now >= self.deadline {
+            return Poll::Ready(());
+        }
+        match inner.wakers.iter_mut().find(|(id, _)| *id == self.id) {
+            Some((_, waker)) => {
+                if !waker.will_wake(cx.waker()) {
+                    waker.clone_from(cx.waker());
+                }
+            }
+            None => inner.wakers.push((self.id, cx.waker().clone())),
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for ManualSleep {
+    fn drop(&mut self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.wakers.retain(|(id, _)| *id != self.id);
+        }
+    }
+}
+
+//
+// Returns `None` if the deadline elapsed before the future completed.
+pub async fn timeout_at<F: Future>(
+    fut: F,
+    deadline: Instant,
+    timer: &dyn Timer,
+) -> Option<F::Output> {
+    let mut fut = pin!(fut);
+    let mut sleep = timer.sleep_until(deadline);
+
+    poll_fn(|cx: &mut Context<'_>| {
+        if let Poll::Ready(output) = fut.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+        sleep.as_mut().poll(cx).map(|()| None)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_manual_timer() {
+        let timer = ManualTimer::new();
+        let deadline = timer.now() + Duration::from_secs(1);
+        let mut sleep = timer.sleep_until(deadline);
+        for _ in 0..3 {
+            assert!(poll_fn(|cx| Poll::Ready(sleep.as_mut().poll(cx).is_pending())).await);
+        }
+        assert_eq!(timer.inner.lock().unwrap().wakers.len(), 1);
+        timer.advance(Duration::from_millis(500));
+        assert!(poll_fn(|cx| Poll::Ready(sleep.as_mut().poll(cx).is_pending())).await);
+        timer.advance(Duration::from_millis(500));
+        sleep.await;
+        assert!(timer.inner.lock().unwrap().wakers.is_empty());
+
+        let sleep = timer.sleep_until(timer.now() + Duration::from_secs(1));
+        assert_eq!(timeout_at(sleep, timer.now(), &timer).await, None);
+        assert!(timer.inner.lock().unwrap().wakers.is_empty());
+    }
+
+    #[cfg(feature = "timer_async_io")]
+    #[tokio::test]
+    async fn test_async_io_timer() {
+        let timer = AsyncIoTimer;
+        let deadline = timer.now() + Duration::from_millis(50);
+        timer.sleep_until(deadline).await;
+        assert!(timer.now() >= deadline);
+
+        let fut = timer.sleep_until(timer.now() + Duration::from_secs(60));
+        let deadline = timer.now() + Duration::from_millis(50);
+        assert_eq!(timeout_at(fut, deadline, &timer).await, None);
+    }
+}
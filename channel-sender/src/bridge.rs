@@ -0,0 +1,46 @@
+//
+// For handing one end of a channel to a std thread and the other to async code. The constructors
+// live in `channel_receiver::bridge`. flume handles work from both sides, so the sender is a
+// `generic::Sender`, `BoundedSender` and `BlockingBoundedSender` at once.
+pub use channel_receiver::bridge::{channel, unbounded_channel, BridgeReceiver, BridgeSender};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        error::SendErrorWithoutFull,
+        generic::Sender,
+        multi_producer::{BlockingBoundedSender, BoundedSender},
+    };
+
+    #[tokio::test]
+    async fn test_blocking_to_async() {
+        let (tx, rx) = channel::<usize>(1);
+        let sender: Box<dyn BlockingBoundedSender<usize> + Send> = Box::new(tx);
+        let handle = std::thread::spawn(move || {
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Ok(()));
+        });
+        assert_eq!(rx.recv_async().await, Ok(1));
+        assert_eq!(rx.recv_async().await, Ok(2));
+        handle.join().unwrap();
+        assert!(rx.recv_async().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_async_to_blocking() {
+        let (tx, rx) = unbounded_channel::<usize>();
+        let sender: Box<dyn BoundedSender<usize>> = Box::new(tx.clone());
+        let handle = std::thread::spawn(move || (rx.recv(), rx.recv(), rx));
+        assert_eq!(sender.send(1).await, Ok(()));
+        assert_eq!(Sender::send(&tx, 2), Ok(()));
+        let (r1, r2, rx) = handle.join().unwrap();
+        assert_eq!((r1, r2), (Ok(1), Ok(2)));
+        drop(rx);
+        assert_eq!(
+            sender.send(3).await,
+            Err(SendErrorWithoutFull::Disconnected(3))
+        );
+    }
+}
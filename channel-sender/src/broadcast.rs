@@ -0,0 +1,20 @@
+use dyn_clone::{clone_trait_object, DynClone};
+
+use crate::{error::SendError, introspect::Introspect};
+
+//
+// Sending never waits on slow receivers, with overflow on they skip the oldest values instead.
+pub trait BroadcastSender<T>: DynClone + Introspect {
+    // Returns how many receivers the value was handed to, fails if there are none. `Full` only
+    // happens with overflow turned off, when the slowest receiver is a whole capacity behind.
+    // The count is approximate, receivers subscribing or dropping during the send can skew it.
+    fn send(&self, t: T) -> Result<usize, SendError<T>>;
+
+    // With overflow on, a full channel drops its oldest value to make room instead of refusing
+    // new ones, so slow receivers skip values rather than holding up the senders.
+    fn overflow(&self) -> bool;
+
+    // Returns `false` if the backend doesn't support the requested mode, leaving it unchanged.
+    fn set_overflow(&mut self, overflow: bool) -> bool;
+}
+clone_trait_object!(<T> BroadcastSender<T>);
@@ -102,6 +102,151 @@ impl<T> SendErrorWithoutFull<T> {
     }
 }
 
+//
+#[derive(Debug, Eq)]
+pub enum SendBatchError<T> {
+    Full(Vec<T>),
+    Closed(Vec<T>),
+    Disconnected(Vec<T>),
+}
+impl<T: core::fmt::Debug> core::fmt::Display for SendBatchError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+impl<T: core::fmt::Debug> std::error::Error for SendBatchError<T> {}
+impl<T: core::cmp::PartialEq> core::cmp::PartialEq for SendBatchError<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Full(v1), Self::Full(v2)) => v1 == v2,
+            (Self::Closed(v1), Self::Closed(v2)) | (Self::Closed(v1), Self::Disconnected(v2)) => {
+                v1 == v2
+            }
+            (Self::Disconnected(v1), Self::Disconnected(v2))
+            | (Self::Disconnected(v1), Self::Closed(v2)) => v1 == v2,
+            _ => false,
+        }
+    }
+}
+
+impl<T> SendBatchError<T> {
+    pub fn is_full(&self) -> bool {
+        matches!(self, Self::Full(_))
+    }
+
+    pub fn is_closed_or_disconnected(&self) -> bool {
+        matches!(self, Self::Closed(_) | Self::Disconnected(_))
+    }
+
+    // The values that were not sent, in their original order.
+    pub fn inner(&self) -> &[T] {
+        match &self {
+            Self::Full(v) => v,
+            Self::Closed(v) => v,
+            Self::Disconnected(v) => v,
+        }
+    }
+    pub fn into_inner(self) -> Vec<T> {
+        match self {
+            Self::Full(v) => v,
+            Self::Closed(v) => v,
+            Self::Disconnected(v) => v,
+        }
+    }
+
+    pub(crate) fn with_remaining(mut self, remaining: impl Iterator<Item = T>) -> Self {
+        match &mut self {
+            Self::Full(v) => v.extend(remaining),
+            Self::Closed(v) => v.extend(remaining),
+            Self::Disconnected(v) => v.extend(remaining),
+        }
+        self
+    }
+}
+
+impl<T> From<SendError<T>> for SendBatchError<T> {
+    fn from(err: SendError<T>) -> Self {
+        match err {
+            SendError::Full(v) => Self::Full(vec![v]),
+            SendError::Closed(v) => Self::Closed(vec![v]),
+            SendError::Disconnected(v) => Self::Disconnected(vec![v]),
+        }
+    }
+}
+
+impl<T> From<SendErrorWithoutFull<T>> for SendBatchError<T> {
+    fn from(err: SendErrorWithoutFull<T>) -> Self {
+        match err {
+            SendErrorWithoutFull::Closed(v) => Self::Closed(vec![v]),
+            SendErrorWithoutFull::Disconnected(v) => Self::Disconnected(vec![v]),
+            SendErrorWithoutFull::UnreachableFull(v) => Self::Full(vec![v]),
+        }
+    }
+}
+
+//
+#[derive(Debug, Eq)]
+pub enum SendTimeoutError<T> {
+    // `None` if the backend's send future dropped the value when the deadline passed.
+    Timeout(Option<T>),
+    Closed(T),
+    Disconnected(T),
+}
+impl<T: core::fmt::Debug> core::fmt::Display for SendTimeoutError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+impl<T: core::fmt::Debug> std::error::Error for SendTimeoutError<T> {}
+impl<T: core::cmp::PartialEq> core::cmp::PartialEq for SendTimeoutError<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Timeout(v1), Self::Timeout(v2)) => v1 == v2,
+            (Self::Closed(v1), Self::Closed(v2)) | (Self::Closed(v1), Self::Disconnected(v2)) => {
+                v1 == v2
+            }
+            (Self::Disconnected(v1), Self::Disconnected(v2))
+            | (Self::Disconnected(v1), Self::Closed(v2)) => v1 == v2,
+            _ => false,
+        }
+    }
+}
+
+impl<T> SendTimeoutError<T> {
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout(_))
+    }
+
+    pub fn is_closed_or_disconnected(&self) -> bool {
+        matches!(self, Self::Closed(_) | Self::Disconnected(_))
+    }
+
+    pub fn inner(&self) -> Option<&T> {
+        match &self {
+            Self::Timeout(v) => v.as_ref(),
+            Self::Closed(v) => Some(v),
+            Self::Disconnected(v) => Some(v),
+        }
+    }
+    pub fn into_inner(self) -> Option<T> {
+        match self {
+            Self::Timeout(v) => v,
+            Self::Closed(v) => Some(v),
+            Self::Disconnected(v) => Some(v),
+        }
+    }
+}
+
+impl<T> From<SendErrorWithoutFull<T>> for SendTimeoutError<T> {
+    fn from(err: SendErrorWithoutFull<T>) -> Self {
+        match err {
+            SendErrorWithoutFull::Closed(v) => Self::Closed(v),
+            SendErrorWithoutFull::Disconnected(v) => Self::Disconnected(v),
+            SendErrorWithoutFull::UnreachableFull(v) => Self::Timeout(Some(v)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +288,41 @@ mod tests {
             SendErrorWithoutFull::Disconnected(1)
         );
     }
+
+    #[test]
+    fn test_send_timeout_error_partial_eq() {
+        assert_eq!(
+            SendTimeoutError::Timeout(Some(1)),
+            SendTimeoutError::Timeout(Some(1))
+        );
+        assert_ne!(
+            SendTimeoutError::Timeout(Some(1)),
+            SendTimeoutError::Timeout(None)
+        );
+        assert_eq!(
+            SendTimeoutError::Closed(1),
+            SendTimeoutError::Disconnected(1)
+        );
+        assert_eq!(
+            SendTimeoutError::Disconnected(1),
+            SendTimeoutError::Closed(1)
+        );
+        assert_ne!(
+            SendTimeoutError::Timeout(Some(1)),
+            SendTimeoutError::Closed(1)
+        );
+        assert_eq!(SendTimeoutError::<usize>::Timeout(None).into_inner(), None);
+    }
+
+    #[test]
+    fn test_send_batch_error_with_remaining() {
+        let err = SendBatchError::from(SendError::Full(1)).with_remaining(vec![2, 3].into_iter());
+        assert_eq!(err, SendBatchError::Full(vec![1, 2, 3]));
+        assert_eq!(err.inner(), &[1, 2, 3]);
+        let err = SendBatchError::from(SendErrorWithoutFull::Disconnected(1))
+            .with_remaining(core::iter::empty());
+        assert_eq!(err, SendBatchError::Closed(vec![1]));
+        assert!(err.is_closed_or_disconnected());
+        assert_eq!(err.into_inner(), vec![1]);
+    }
 }
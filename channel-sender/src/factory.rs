@@ -0,0 +1,78 @@
+use channel_receiver::{generic::AsyncReceiver, one_shot::AsyncReceiver as OneshotAsyncReceiver};
+
+use crate::{
+    multi_producer::{BoundedSender, UnboundedSender},
+    one_shot::BoxSender as OneshotBoxSender,
+};
+
+//
+pub type BoxBoundedSender<T> = Box<dyn BoundedSender<T> + Send + Sync>;
+pub type BoxUnboundedSender<T> = Box<dyn UnboundedSender<T> + Send + Sync>;
+pub type BoxAsyncReceiver<T> = Box<dyn AsyncReceiver<T> + Send>;
+
+pub type BoxOneshotSender<T> = Box<dyn OneshotBoxSender<T> + Send>;
+pub type BoxOneshotReceiver<T> = Box<dyn OneshotAsyncReceiver<T> + Send + Unpin>;
+
+//
+// Lets code that creates channels stay generic over the backend, the application picks one of the
+// factories behind the `factory_*` features.
+pub trait ChannelFactory {
+    // Panics if `capacity` is zero, not every backend has rendezvous channels.
+    fn bounded<T>(&self, capacity: usize) -> (BoxBoundedSender<T>, BoxAsyncReceiver<T>)
+    where
+        T: Send + 'static;
+
+    fn unbounded<T>(&self) -> (BoxUnboundedSender<T>, BoxAsyncReceiver<T>)
+    where
+        T: Send + 'static;
+
+    // `None` if the backend has no oneshot channel.
+    fn oneshot<T>(&self) -> Option<(BoxOneshotSender<T>, BoxOneshotReceiver<T>)>
+    where
+        T: Send + 'static,
+    {
+        None
+    }
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "factory_tokio",
+        feature = "factory_async_channel",
+        feature = "factory_flume",
+        feature = "factory_futures_channel",
+        feature = "factory_kanal"
+    )
+))]
+pub(crate) mod factory_tests {
+    use super::*;
+
+    // The checks every backend's factory has to pass.
+    pub(crate) async fn test_factory(factory: impl ChannelFactory) {
+        {
+            let (tx, mut rx) = factory.bounded::<usize>(1);
+            assert_eq!(tx.send(1).await, Ok(()));
+            assert_eq!(rx.recv().await, Some(1));
+            drop(tx);
+            assert_eq!(rx.recv().await, None);
+        }
+        {
+            let (tx, mut rx) = factory.unbounded::<usize>();
+            assert_eq!(tx.send(1), Ok(()));
+            assert_eq!(tx.send(2), Ok(()));
+            assert_eq!(rx.recv().await, Some(1));
+            assert_eq!(rx.recv().await, Some(2));
+            drop(tx);
+            assert_eq!(rx.recv().await, None);
+        }
+        if let Some((tx, rx)) = factory.oneshot::<usize>() {
+            assert_eq!(tx.send(1), Ok(()));
+            assert_eq!(rx.await, Ok(1));
+        }
+    }
+
+    pub(crate) fn test_zero_capacity(factory: impl ChannelFactory) {
+        let _ = factory.bounded::<usize>(0);
+    }
+}
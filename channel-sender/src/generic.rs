@@ -1,13 +1,13 @@
 use dyn_clone::{clone_trait_object, DynClone};
 
-use crate::error::SendError;
+use crate::{error::SendError, introspect::Introspect};
 
 //
-pub trait Sender<T> {
+pub trait Sender<T>: Introspect {
     fn send(&self, t: T) -> Result<(), SendError<T>>;
 }
 
-pub trait CloneableSender<T>: DynClone {
+pub trait CloneableSender<T>: DynClone + Introspect {
     fn send(&self, t: T) -> Result<(), SendError<T>>;
 }
 clone_trait_object!(<T> CloneableSender<T>);
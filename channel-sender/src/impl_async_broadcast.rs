@@ -0,0 +1,183 @@
+pub use async_broadcast::Sender as AsyncBroadcastSender;
+use async_broadcast::TrySendError;
+
+//
+mod multi_producer_impl {
+    use super::*;
+
+    use crate::{
+        error::{SendError, SendErrorWithoutFull},
+        multi_producer::{BlockingBoundedSender, NativeBoundedSender},
+    };
+
+    impl<T: Clone> NativeBoundedSender<T> for AsyncBroadcastSender<T> {
+        async fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>
+        where
+            T: Send,
+        {
+            AsyncBroadcastSender::broadcast(self, t)
+                .await
+                .map(|_| ())
+                .map_err(|err| SendErrorWithoutFull::Closed(err.0))
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            AsyncBroadcastSender::try_broadcast(self, t)
+                .map(|_| ())
+                .map_err(Into::into)
+        }
+    }
+
+    impl<T: Clone> BlockingBoundedSender<T> for AsyncBroadcastSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            AsyncBroadcastSender::broadcast_blocking(self, t)
+                .map(|_| ())
+                .map_err(|err| SendErrorWithoutFull::Closed(err.0))
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            AsyncBroadcastSender::try_broadcast(self, t)
+                .map(|_| ())
+                .map_err(Into::into)
+        }
+    }
+}
+
+//
+mod broadcast_impl {
+    use super::*;
+
+    use crate::{broadcast::BroadcastSender, error::SendError};
+
+    impl<T: Clone> BroadcastSender<T> for AsyncBroadcastSender<T> {
+        // With overflow on, the value pushed out to make room is dropped. async-broadcast doesn't
+        // report who got the value, so the count is the active receivers right after the send.
+        fn send(&self, t: T) -> Result<usize, SendError<T>> {
+            AsyncBroadcastSender::try_broadcast(self, t)
+                .map(|_| self.receiver_count())
+                .map_err(Into::into)
+        }
+
+        fn overflow(&self) -> bool {
+            AsyncBroadcastSender::overflow(self)
+        }
+
+        fn set_overflow(&mut self, overflow: bool) -> bool {
+            AsyncBroadcastSender::set_overflow(self, overflow);
+            true
+        }
+    }
+}
+
+//
+mod generic_impl {
+    use super::*;
+
+    use crate::{
+        error::SendError,
+        generic::{CloneableSender, Sender},
+    };
+
+    impl<T: Clone> Sender<T> for AsyncBroadcastSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            AsyncBroadcastSender::try_broadcast(self, t)
+                .map(|_| ())
+                .map_err(Into::into)
+        }
+    }
+
+    impl<T: Clone> CloneableSender<T> for AsyncBroadcastSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            AsyncBroadcastSender::try_broadcast(self, t)
+                .map(|_| ())
+                .map_err(Into::into)
+        }
+    }
+}
+
+//
+mod error_convert {
+    use super::*;
+
+    use crate::error::SendError;
+
+    // `Inactive` means only inactive receivers are left, so there is nobody to hand the value to.
+    impl<T> From<TrySendError<T>> for SendError<T> {
+        fn from(err: TrySendError<T>) -> Self {
+            match err {
+                TrySendError::Full(v) => Self::Full(v),
+                TrySendError::Closed(v) => Self::Closed(v),
+                TrySendError::Inactive(v) => Self::Disconnected(v),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod multi_producer_impl_tests {
+    use crate::{
+        error::{SendError, SendErrorWithoutFull},
+        multi_producer::BoundedSender,
+    };
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, mut rx) = async_broadcast::broadcast(1);
+            let sender: Box<dyn BoundedSender<usize> + Send + Sync> = Box::new(tx);
+            assert_eq!(sender.send(1).await, Ok(()));
+            assert_eq!(sender.try_send(2), Err(SendError::Full(2)));
+            assert_eq!(sender.len(), Some(1));
+            assert_eq!(sender.capacity(), Some(0));
+            assert_eq!(rx.recv().await, Ok(1));
+
+            let inactive = rx.deactivate();
+            assert_eq!(sender.try_send(3), Err(SendError::Disconnected(3)));
+            drop(inactive);
+            assert_eq!(sender.send(4).await, Err(SendErrorWithoutFull::Closed(4)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod broadcast_impl_tests {
+    use crate::{broadcast::BroadcastSender, error::SendError, generic::Sender};
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, mut rx1) = async_broadcast::broadcast(1);
+            let mut rx2 = rx1.clone();
+            let mut sender: Box<dyn BroadcastSender<usize> + Send + Sync> = Box::new(tx);
+            assert_eq!(sender.receiver_count(), Some(2));
+            assert_eq!(sender.sender_count(), Some(1));
+            assert!(!sender.overflow());
+            assert_eq!(sender.send(1), Ok(2));
+            assert_eq!(sender.send(2), Err(SendError::Full(2)));
+
+            assert!(sender.set_overflow(true));
+            assert!(sender.overflow());
+            assert_eq!(sender.send(3), Ok(2));
+            assert_eq!(
+                rx1.recv().await,
+                Err(async_broadcast::RecvError::Overflowed(1))
+            );
+            assert_eq!(rx1.recv().await, Ok(3));
+            assert_eq!(
+                rx2.try_recv(),
+                Err(async_broadcast::TryRecvError::Overflowed(1))
+            );
+            assert_eq!(rx2.try_recv(), Ok(3));
+
+            drop(rx1);
+            drop(rx2);
+            assert!(sender.send(4).is_err());
+        }
+        {
+            let (tx, mut rx) = async_broadcast::broadcast(1);
+            let sender: Box<dyn Sender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(rx.recv().await, Ok(1));
+        }
+    }
+}
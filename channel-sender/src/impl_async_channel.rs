@@ -1,17 +1,88 @@
-pub use async_channel::Sender as AsyncChannelSender;
+use std::sync::{Arc, Mutex, MutexGuard};
+
 use async_channel::TrySendError;
+pub use async_channel::{Sender as AsyncChannelSender, WeakSender as AsyncChannelWeakSender};
+
+use crate::timer::Timer;
+
+//
+// async-channel can't set a slot aside, so permits are counted here instead: a send or a reserve
+// through the wrapper only goes ahead while the queued messages plus the outstanding permits leave
+// room. That only holds if every producer sends through a clone of the same wrapper.
+//
+// Nothing in async-channel says when the receiver frees up a slot, so waiting for room retries on
+// `timer` with a backoff.
+pub struct AsyncChannelReservableSender<T> {
+    sender: AsyncChannelSender<T>,
+    max_capacity: usize,
+    reserved: Arc<Mutex<usize>>,
+    timer: Arc<dyn Timer>,
+}
+
+impl<T> AsyncChannelReservableSender<T> {
+    pub fn new(sender: AsyncChannelSender<T>, timer: Arc<dyn Timer>) -> Self {
+        let max_capacity = sender
+            .capacity()
+            .expect("AsyncChannelReservableSender needs a bounded channel");
+        Self {
+            sender,
+            max_capacity,
+            reserved: Arc::new(Mutex::new(0)),
+            timer,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, usize> {
+        self.reserved
+            .lock()
+            .expect("AsyncChannelReservableSender lock poisoned")
+    }
+
+    fn has_room(&self, reserved: usize) -> bool {
+        self.sender.len() + reserved < self.max_capacity
+    }
+}
+
+impl<T> Clone for AsyncChannelReservableSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            max_capacity: self.max_capacity,
+            reserved: self.reserved.clone(),
+            timer: self.timer.clone(),
+        }
+    }
+}
+
+pub struct AsyncChannelPermit<'a, T> {
+    wrapper: &'a AsyncChannelReservableSender<T>,
+    sent: bool,
+}
+
+impl<T> Drop for AsyncChannelPermit<'_, T> {
+    fn drop(&mut self) {
+        if !self.sent {
+            *self.wrapper.lock() -= 1;
+        }
+    }
+}
 
 //
 mod multi_producer_impl {
     use super::*;
 
+    use core::{future::Future, time::Duration};
+    use std::time::Instant;
+
     use crate::{
-        error::{SendError, SendErrorWithoutFull},
-        multi_producer::{BoundedSender, UnboundedSender},
+        error::{SendError, SendErrorWithoutFull, SendTimeoutError},
+        multi_producer::{
+            BlockingBoundedSender, BoundedSender, BoxPermit, BoxWeakSender, NativeBoundedSender,
+            Permit, ReservableBoundedSender, UnboundedSender, WeakSender,
+        },
     };
 
-    #[async_trait::async_trait]
-    impl<T> BoundedSender<T> for AsyncChannelSender<T> {
+    impl<T> NativeBoundedSender<T> for AsyncChannelSender<T> {
         async fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>
         where
             T: Send,
@@ -24,6 +95,160 @@ mod multi_producer_impl {
         fn try_send(&self, t: T) -> Result<(), SendError<T>> {
             AsyncChannelSender::try_send(self, t).map_err(Into::into)
         }
+
+        fn downgrade(&self) -> Option<BoxWeakSender<dyn BoundedSender<T> + Send + Sync>>
+        where
+            T: Send + 'static,
+        {
+            Some(Box::new(AsyncChannelSender::downgrade(self)))
+        }
+    }
+
+    impl<T> BlockingBoundedSender<T> for AsyncChannelSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            AsyncChannelSender::send_blocking(self, t)
+                .map_err(|err| SendErrorWithoutFull::Closed(err.0))
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            AsyncChannelSender::try_send(self, t).map_err(Into::into)
+        }
+    }
+
+    const RETRY_BACKOFF_MIN: Duration = Duration::from_millis(1);
+    const RETRY_BACKOFF_MAX: Duration = Duration::from_millis(50);
+
+    // Keeps calling `attempt` while it reports `Full`, parking on `timer` in between and backing off
+    // from `RETRY_BACKOFF_MIN` to `RETRY_BACKOFF_MAX`. Reports `Full` once the deadline passes.
+    async fn retry_until<T, R>(
+        mut attempt: impl FnMut(T) -> Result<R, SendError<T>>,
+        mut t: T,
+        deadline: Option<Instant>,
+        timer: &dyn Timer,
+    ) -> Result<R, SendError<T>> {
+        let mut backoff = RETRY_BACKOFF_MIN;
+        loop {
+            t = match attempt(t) {
+                Err(SendError::Full(t)) => t,
+                result => return result,
+            };
+
+            let now = timer.now();
+            let retry_at = now.checked_add(backoff);
+            let wake_at = match (deadline, retry_at) {
+                (Some(deadline), _) if now >= deadline => return Err(SendError::Full(t)),
+                (Some(deadline), Some(retry_at)) => deadline.min(retry_at),
+                (Some(deadline), None) => deadline,
+                (None, Some(retry_at)) => retry_at,
+                (None, None) => now,
+            };
+            timer.sleep_until(wake_at).await;
+            backoff = (backoff * 2).min(RETRY_BACKOFF_MAX);
+        }
+    }
+
+    impl<T> NativeBoundedSender<T> for AsyncChannelReservableSender<T> {
+        async fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>
+        where
+            T: Send,
+        {
+            retry_until(
+                |t| NativeBoundedSender::try_send(self, t),
+                t,
+                None,
+                &*self.timer,
+            )
+            .await
+            .map_err(|err| match err {
+                SendError::Full(t) => SendErrorWithoutFull::UnreachableFull(t),
+                SendError::Closed(t) => SendErrorWithoutFull::Closed(t),
+                SendError::Disconnected(t) => SendErrorWithoutFull::Disconnected(t),
+            })
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            let reserved = self.lock();
+            if self.sender.is_closed() {
+                return Err(SendError::Closed(t));
+            }
+            if !self.has_room(*reserved) {
+                return Err(SendError::Full(t));
+            }
+            AsyncChannelSender::try_send(&self.sender, t).map_err(Into::into)
+        }
+
+        // Retrying `try_send` keeps the value, so it comes back with `Timeout`.
+        #[allow(clippy::manual_async_fn)]
+        fn send_deadline<'a>(
+            &'a self,
+            t: T,
+            deadline: Instant,
+            timer: &'a dyn Timer,
+        ) -> impl Future<Output = Result<(), SendTimeoutError<T>>> + Send + 'a
+        where
+            T: Send + 'a,
+            Self: Sync,
+        {
+            async move {
+                retry_until(
+                    |t| NativeBoundedSender::try_send(self, t),
+                    t,
+                    Some(deadline),
+                    timer,
+                )
+                .await
+                .map_err(|err| match err {
+                    SendError::Full(t) => SendTimeoutError::Timeout(Some(t)),
+                    SendError::Closed(t) => SendTimeoutError::Closed(t),
+                    SendError::Disconnected(t) => SendTimeoutError::Disconnected(t),
+                })
+            }
+        }
+    }
+
+    impl<T> AsyncChannelReservableSender<T> {
+        fn try_reserve_permit(&self) -> Result<AsyncChannelPermit<'_, T>, SendError<()>> {
+            let mut reserved = self.lock();
+            if self.sender.is_closed() {
+                return Err(SendError::Closed(()));
+            }
+            if !self.has_room(*reserved) {
+                return Err(SendError::Full(()));
+            }
+            *reserved += 1;
+            Ok(AsyncChannelPermit {
+                wrapper: self,
+                sent: false,
+            })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<T: Send> ReservableBoundedSender<T> for AsyncChannelReservableSender<T> {
+        async fn reserve(&self) -> Result<BoxPermit<'_, T>, SendErrorWithoutFull<()>> {
+            match retry_until(|()| self.try_reserve_permit(), (), None, &*self.timer).await {
+                Ok(permit) => Ok(Box::new(permit)),
+                Err(SendError::Full(())) => Err(SendErrorWithoutFull::UnreachableFull(())),
+                Err(SendError::Closed(())) => Err(SendErrorWithoutFull::Closed(())),
+                Err(SendError::Disconnected(())) => Err(SendErrorWithoutFull::Disconnected(())),
+            }
+        }
+
+        fn try_reserve(&self) -> Result<BoxPermit<'_, T>, SendError<()>> {
+            Ok(Box::new(self.try_reserve_permit()?))
+        }
+    }
+
+    impl<T> Permit<T> for AsyncChannelPermit<'_, T> {
+        fn send(mut self: Box<Self>, t: T) {
+            let mut reserved = self.wrapper.lock();
+            // The permit's slot is still counted, so there is room unless the receiver went away,
+            // in which case the value is dropped like tokio does.
+            let _ = AsyncChannelSender::try_send(&self.wrapper.sender, t);
+            *reserved -= 1;
+            drop(reserved);
+            self.sent = true;
+        }
     }
 
     impl<T> UnboundedSender<T> for AsyncChannelSender<T> {
@@ -41,6 +266,67 @@ mod multi_producer_impl {
                 },
             }
         }
+
+        fn downgrade(&self) -> Option<BoxWeakSender<dyn UnboundedSender<T> + Send + Sync>>
+        where
+            T: Send + 'static,
+        {
+            Some(Box::new(AsyncChannelSender::downgrade(self)))
+        }
+    }
+
+    // The same weak handle upgrades to either flavour, matching whichever sender it came from.
+    impl<T: Send + 'static> WeakSender<dyn BoundedSender<T> + Send + Sync>
+        for AsyncChannelWeakSender<T>
+    {
+        fn upgrade(&self) -> Option<Box<dyn BoundedSender<T> + Send + Sync>> {
+            AsyncChannelWeakSender::upgrade(self).map(|sender| Box::new(sender) as _)
+        }
+    }
+
+    impl<T: Send + 'static> WeakSender<dyn UnboundedSender<T> + Send + Sync>
+        for AsyncChannelWeakSender<T>
+    {
+        fn upgrade(&self) -> Option<Box<dyn UnboundedSender<T> + Send + Sync>> {
+            AsyncChannelWeakSender::upgrade(self).map(|sender| Box::new(sender) as _)
+        }
+    }
+}
+
+//
+mod introspect_impl {
+    use super::*;
+
+    use crate::introspect::Introspect;
+
+    // Outstanding permits count towards the length, as with tokio.
+    impl<T> Introspect for AsyncChannelReservableSender<T> {
+        fn len(&self) -> Option<usize> {
+            Some(self.sender.len() + *self.lock())
+        }
+
+        fn capacity(&self) -> Option<usize> {
+            Some(
+                self.max_capacity
+                    .saturating_sub(self.sender.len() + *self.lock()),
+            )
+        }
+
+        fn max_capacity(&self) -> Option<usize> {
+            Some(self.max_capacity)
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(self.sender.is_closed())
+        }
+
+        fn sender_count(&self) -> Option<usize> {
+            Some(self.sender.sender_count())
+        }
+
+        fn receiver_count(&self) -> Option<usize> {
+            Some(self.sender.receiver_count())
+        }
     }
 }
 
@@ -82,11 +368,42 @@ mod error_convert {
     }
 }
 
+//
+#[cfg(feature = "factory_async_channel")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsyncChannelFactory;
+
+#[cfg(feature = "factory_async_channel")]
+mod factory_impl {
+    use super::*;
+
+    use crate::factory::{BoxAsyncReceiver, BoxBoundedSender, BoxUnboundedSender, ChannelFactory};
+
+    impl ChannelFactory for AsyncChannelFactory {
+        fn bounded<T>(&self, capacity: usize) -> (BoxBoundedSender<T>, BoxAsyncReceiver<T>)
+        where
+            T: Send + 'static,
+        {
+            assert!(capacity > 0, "capacity must be greater than zero");
+            let (tx, rx) = async_channel::bounded(capacity);
+            (Box::new(tx), Box::new(rx))
+        }
+
+        fn unbounded<T>(&self) -> (BoxUnboundedSender<T>, BoxAsyncReceiver<T>)
+        where
+            T: Send + 'static,
+        {
+            let (tx, rx) = async_channel::unbounded();
+            (Box::new(tx), Box::new(rx))
+        }
+    }
+}
+
 #[cfg(test)]
 mod multi_producer_impl_tests {
     use crate::{
         error::{SendError, SendErrorWithoutFull},
-        multi_producer::{BoundedSender, UnboundedSender},
+        multi_producer::{BlockingBoundedSender, BoundedSender, UnboundedSender},
     };
 
     #[tokio::test]
@@ -109,6 +426,124 @@ mod multi_producer_impl_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_send_timeout_with_manual_timer() {
+        use core::time::Duration;
+
+        use crate::{error::SendTimeoutError, timer::ManualTimer};
+
+        {
+            let (tx, rx) = async_channel::bounded(1);
+            let sender: Box<dyn BoundedSender<usize> + Send + Sync> = Box::new(tx);
+            let timer = ManualTimer::new();
+            assert_eq!(
+                sender
+                    .send_timeout(1, Duration::from_secs(60), &timer)
+                    .await,
+                Ok(())
+            );
+            let handle = tokio::spawn({
+                let sender = sender.clone();
+                let timer = timer.clone();
+                async move {
+                    sender
+                        .send_timeout(2, Duration::from_secs(60), &timer)
+                        .await
+                }
+            });
+            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+            assert!(!handle.is_finished());
+            timer.advance(Duration::from_secs(60));
+            // async-channel's send future drops the value when the deadline cuts it off.
+            assert_eq!(handle.await.unwrap(), Err(SendTimeoutError::Timeout(None)));
+
+            let handle = tokio::spawn({
+                let sender = sender.clone();
+                let timer = timer.clone();
+                async move {
+                    sender
+                        .send_timeout(2, Duration::from_secs(60), &timer)
+                        .await
+                }
+            });
+            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+            assert!(!handle.is_finished());
+            // Waits on async-channel itself, so room is picked up without the clock moving.
+            assert_eq!(rx.recv().await, Ok(1));
+            assert_eq!(handle.await.unwrap(), Ok(()));
+            assert_eq!(rx.recv().await, Ok(2));
+            drop(rx);
+            assert_eq!(
+                sender
+                    .send_timeout(3, Duration::from_secs(60), &timer)
+                    .await,
+                Err(SendTimeoutError::Closed(3))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reserve_with_reservable_sender() {
+        use core::time::Duration;
+        use std::sync::Arc;
+
+        use crate::{
+            error::SendTimeoutError, impl_async_channel::AsyncChannelReservableSender,
+            multi_producer::ReservableBoundedSender, timer::ManualTimer,
+        };
+
+        {
+            let (tx, rx) = async_channel::bounded(1);
+            let timer = ManualTimer::new();
+            let sender: Box<dyn ReservableBoundedSender<usize> + Send + Sync> = Box::new(
+                AsyncChannelReservableSender::new(tx, Arc::new(timer.clone())),
+            );
+            let other = sender.clone();
+            let permit = sender.try_reserve().unwrap();
+            assert_eq!(sender.len(), Some(1));
+            assert_eq!(sender.capacity(), Some(0));
+            assert_eq!(sender.try_send(1), Err(SendError::Full(1)));
+            assert_eq!(other.try_send(1), Err(SendError::Full(1)));
+            assert_eq!(other.try_reserve().err(), Some(SendError::Full(())));
+            assert_eq!(
+                other.send_timeout(1, Duration::ZERO, &timer).await,
+                Err(SendTimeoutError::Timeout(Some(1)))
+            );
+            assert!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), other.reserve())
+                    .await
+                    .is_err()
+            );
+            permit.send(1);
+            assert_eq!(rx.recv().await, Ok(1));
+
+            let permit = sender.reserve().await.unwrap();
+            assert_eq!(other.try_send(2), Err(SendError::Full(2)));
+            drop(permit);
+            assert_eq!(other.try_send(2), Ok(()));
+
+            let handle = tokio::spawn({
+                let other = other.clone();
+                async move { other.reserve().await.map(|permit| permit.send(3)) }
+            });
+            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+            assert_eq!(rx.recv().await, Ok(2));
+            assert!(!handle.is_finished());
+            // The retry only comes around once the clock reaches it.
+            timer.advance(Duration::from_millis(1));
+            assert_eq!(handle.await.unwrap(), Ok(()));
+            assert_eq!(rx.recv().await, Ok(3));
+
+            drop(rx);
+            assert_eq!(
+                sender.reserve().await.err(),
+                Some(SendErrorWithoutFull::Closed(()))
+            );
+            assert_eq!(sender.try_reserve().err(), Some(SendError::Closed(())));
+            assert_eq!(sender.try_send(4), Err(SendError::Closed(4)));
+        }
+    }
+
     #[tokio::test]
     async fn test_with_unbounded() {
         {
@@ -123,6 +558,28 @@ mod multi_producer_impl_tests {
             assert_eq!(sender.send(3), Err(SendErrorWithoutFull::Closed(3)));
         }
     }
+
+    #[test]
+    fn test_blocking_with_bounded() {
+        {
+            let (tx, rx) = async_channel::bounded(1);
+            let sender: Box<dyn BlockingBoundedSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.try_send(2), Err(SendError::Full(2)));
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                assert_eq!(rx.recv_blocking(), Ok(1));
+                rx
+            });
+            assert_eq!(sender.send(2), Ok(()));
+            let rx = handle.join().unwrap();
+            assert_eq!(rx.recv_blocking(), Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendErrorWithoutFull::Closed(3)));
+            assert_eq!(sender.try_send(3), Err(SendError::Closed(3)));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -180,3 +637,123 @@ mod generic_impl_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod introspect_impl_tests {
+    use crate::multi_producer::{BoundedSender, UnboundedSender};
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = async_channel::bounded(2);
+            let sender: Box<dyn BoundedSender<usize>> = Box::new(tx);
+            assert_eq!(sender.is_empty(), Some(true));
+            assert_eq!(sender.send(1).await, Ok(()));
+            assert_eq!(sender.len(), Some(1));
+            assert_eq!(sender.is_empty(), Some(false));
+            assert_eq!(sender.capacity(), Some(1));
+            assert_eq!(sender.max_capacity(), Some(2));
+            assert_eq!(sender.is_closed(), Some(false));
+            drop(rx);
+            assert_eq!(sender.is_closed(), Some(true));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded() {
+        {
+            let (tx, _rx) = async_channel::unbounded();
+            let sender: Box<dyn UnboundedSender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.len(), Some(1));
+            assert_eq!(sender.capacity(), None);
+            assert_eq!(sender.max_capacity(), None);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_counts_with_bounded() {
+        {
+            let (tx, rx) = async_channel::bounded::<usize>(1);
+            let sender: Box<dyn BoundedSender<usize> + Send + Sync> = Box::new(tx);
+            let cloned = sender.clone();
+            assert_eq!(sender.sender_count(), Some(2));
+            assert_eq!(sender.receiver_count(), Some(1));
+            drop(rx);
+            assert_eq!(sender.receiver_count(), Some(0));
+            assert_eq!(sender.same_channel(&cloned), None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod closed_impl_tests {
+    use crate::multi_producer::BoundedSender;
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, _rx) = async_channel::bounded::<usize>(1);
+            let sender: Box<dyn BoundedSender<usize> + Send + Sync> = Box::new(tx);
+            assert!(sender.closed().is_none());
+        }
+    }
+}
+
+#[cfg(test)]
+mod weak_impl_tests {
+    use crate::multi_producer::{BoundedSender, UnboundedSender};
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = async_channel::bounded(1);
+            let sender: Box<dyn BoundedSender<usize> + Send + Sync> = Box::new(tx);
+            let weak = sender.downgrade().unwrap();
+
+            let upgraded = weak.upgrade().unwrap();
+            assert_eq!(upgraded.send(1).await, Ok(()));
+            assert_eq!(rx.recv().await, Ok(1));
+            drop(upgraded);
+
+            drop(sender);
+            assert!(rx.is_closed());
+            assert!(rx.recv().await.is_err());
+            assert!(weak.upgrade().is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded() {
+        {
+            let (tx, rx) = async_channel::unbounded();
+            let sender: Box<dyn UnboundedSender<usize> + Send + Sync> = Box::new(tx);
+            let weak = sender.downgrade().unwrap();
+
+            assert_eq!(weak.upgrade().unwrap().send(1), Ok(()));
+            assert_eq!(rx.recv().await, Ok(1));
+
+            drop(sender);
+            assert!(rx.recv().await.is_err());
+            assert!(weak.clone().upgrade().is_none());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "factory_async_channel"))]
+mod factory_impl_tests {
+    use super::*;
+
+    use crate::factory::factory_tests;
+
+    #[tokio::test]
+    async fn test_with_factory() {
+        factory_tests::test_factory(AsyncChannelFactory).await;
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be greater than zero")]
+    fn test_with_zero_capacity() {
+        factory_tests::test_zero_capacity(AsyncChannelFactory);
+    }
+}
@@ -0,0 +1,178 @@
+pub use crossbeam_channel::Sender as CrossbeamSender;
+use crossbeam_channel::TrySendError;
+
+//
+mod multi_producer_impl {
+    use super::*;
+
+    use crate::{
+        error::{SendError, SendErrorWithoutFull},
+        multi_producer::{BlockingBoundedSender, UnboundedSender},
+    };
+
+    impl<T> BlockingBoundedSender<T> for CrossbeamSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            CrossbeamSender::send(self, t).map_err(|err| SendErrorWithoutFull::Disconnected(err.0))
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            CrossbeamSender::try_send(self, t).map_err(Into::into)
+        }
+    }
+
+    impl<T> UnboundedSender<T> for CrossbeamSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            debug_assert!(
+                self.capacity().is_none(),
+                "Unbounded channels are never full. Make sure you are using `crossbeam_channel::unbounded`."
+            );
+
+            match CrossbeamSender::try_send(self, t) {
+                Ok(_) => Ok(()),
+                Err(err) => match err {
+                    TrySendError::Full(v) => Err(SendErrorWithoutFull::UnreachableFull(v)),
+                    TrySendError::Disconnected(v) => Err(SendErrorWithoutFull::Disconnected(v)),
+                },
+            }
+        }
+    }
+}
+
+//
+mod generic_impl {
+    use super::*;
+
+    use crate::{
+        error::SendError,
+        generic::{CloneableSender, Sender},
+    };
+
+    impl<T> Sender<T> for CrossbeamSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            CrossbeamSender::try_send(self, t).map_err(Into::into)
+        }
+    }
+
+    impl<T> CloneableSender<T> for CrossbeamSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            CrossbeamSender::try_send(self, t).map_err(Into::into)
+        }
+    }
+}
+
+//
+mod error_convert {
+    use super::*;
+
+    use crate::error::SendError;
+
+    impl<T> From<TrySendError<T>> for SendError<T> {
+        fn from(err: TrySendError<T>) -> Self {
+            match err {
+                TrySendError::Full(v) => Self::Full(v),
+                TrySendError::Disconnected(v) => Self::Disconnected(v),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod multi_producer_impl_tests {
+    use crate::{
+        error::{SendError, SendErrorWithoutFull},
+        multi_producer::{BlockingBoundedSender, UnboundedSender},
+    };
+
+    #[test]
+    fn test_with_unbounded() {
+        {
+            let (tx, rx) = crossbeam_channel::unbounded();
+            let sender: Box<dyn UnboundedSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Ok(()));
+            assert_eq!(rx.recv(), Ok(1));
+            assert_eq!(rx.recv(), Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendErrorWithoutFull::Disconnected(3)));
+        }
+    }
+
+    #[test]
+    fn test_with_bounded() {
+        {
+            let (tx, rx) = crossbeam_channel::bounded(1);
+            let sender: Box<dyn BlockingBoundedSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.try_send(2), Err(SendError::Full(2)));
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                assert_eq!(rx.recv(), Ok(1));
+                rx
+            });
+            assert_eq!(sender.send(2), Ok(()));
+            let rx = handle.join().unwrap();
+            assert_eq!(rx.recv(), Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendErrorWithoutFull::Disconnected(3)));
+            assert_eq!(sender.try_send(3), Err(SendError::Disconnected(3)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod generic_impl_tests {
+    use crate::{
+        error::SendError,
+        generic::{CloneableSender, Sender},
+    };
+
+    #[test]
+    fn test_with_bounded() {
+        {
+            let (tx, rx) = crossbeam_channel::bounded(1);
+            let sender: Box<dyn Sender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Err(SendError::Full(2)));
+            assert_eq!(rx.recv(), Ok(1));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendError::Disconnected(3)));
+        }
+        {
+            let (tx, rx) = crossbeam_channel::bounded(1);
+            let sender: Box<dyn CloneableSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Err(SendError::Full(2)));
+            assert_eq!(rx.recv(), Ok(1));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendError::Disconnected(3)));
+        }
+    }
+
+    #[test]
+    fn test_with_unbounded() {
+        {
+            let (tx, rx) = crossbeam_channel::unbounded();
+            let sender: Box<dyn Sender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Ok(()));
+            assert_eq!(rx.recv(), Ok(1));
+            assert_eq!(rx.recv(), Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendError::Disconnected(3)));
+        }
+        {
+            let (tx, rx) = crossbeam_channel::unbounded();
+            let sender: Box<dyn CloneableSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Ok(()));
+            assert_eq!(rx.recv(), Ok(1));
+            assert_eq!(rx.recv(), Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendError::Disconnected(3)));
+        }
+    }
+}
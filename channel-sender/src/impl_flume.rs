@@ -0,0 +1,265 @@
+pub use flume::Sender as FlumeSender;
+use flume::TrySendError;
+
+//
+mod multi_producer_impl {
+    use super::*;
+
+    use crate::{
+        error::{SendError, SendErrorWithoutFull},
+        multi_producer::{BlockingBoundedSender, NativeBoundedSender, UnboundedSender},
+    };
+
+    impl<T> NativeBoundedSender<T> for FlumeSender<T> {
+        async fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>
+        where
+            T: Send,
+        {
+            FlumeSender::send_async(self, t)
+                .await
+                .map_err(|err| SendErrorWithoutFull::Disconnected(err.0))
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            FlumeSender::try_send(self, t).map_err(Into::into)
+        }
+    }
+
+    impl<T> BlockingBoundedSender<T> for FlumeSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            FlumeSender::send(self, t).map_err(|err| SendErrorWithoutFull::Disconnected(err.0))
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            FlumeSender::try_send(self, t).map_err(Into::into)
+        }
+    }
+
+    impl<T> UnboundedSender<T> for FlumeSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            debug_assert!(
+                self.capacity().is_none(),
+                "Unbounded channels are never full. Make sure you are using `flume::unbounded`."
+            );
+
+            match FlumeSender::try_send(self, t) {
+                Ok(_) => Ok(()),
+                Err(err) => match err {
+                    TrySendError::Full(v) => Err(SendErrorWithoutFull::UnreachableFull(v)),
+                    TrySendError::Disconnected(v) => Err(SendErrorWithoutFull::Disconnected(v)),
+                },
+            }
+        }
+    }
+}
+
+//
+mod generic_impl {
+    use super::*;
+
+    use crate::{
+        error::SendError,
+        generic::{CloneableSender, Sender},
+    };
+
+    impl<T> Sender<T> for FlumeSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            FlumeSender::try_send(self, t).map_err(Into::into)
+        }
+    }
+
+    impl<T> CloneableSender<T> for FlumeSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            FlumeSender::try_send(self, t).map_err(Into::into)
+        }
+    }
+}
+
+//
+mod error_convert {
+    use super::*;
+
+    use crate::error::SendError;
+
+    impl<T> From<TrySendError<T>> for SendError<T> {
+        fn from(err: TrySendError<T>) -> Self {
+            match err {
+                TrySendError::Full(v) => Self::Full(v),
+                TrySendError::Disconnected(v) => Self::Disconnected(v),
+            }
+        }
+    }
+}
+
+//
+#[cfg(feature = "factory_flume")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FlumeFactory;
+
+#[cfg(feature = "factory_flume")]
+mod factory_impl {
+    use super::*;
+
+    use crate::factory::{BoxAsyncReceiver, BoxBoundedSender, BoxUnboundedSender, ChannelFactory};
+
+    impl ChannelFactory for FlumeFactory {
+        fn bounded<T>(&self, capacity: usize) -> (BoxBoundedSender<T>, BoxAsyncReceiver<T>)
+        where
+            T: Send + 'static,
+        {
+            assert!(capacity > 0, "capacity must be greater than zero");
+            let (tx, rx) = flume::bounded(capacity);
+            (Box::new(tx), Box::new(rx))
+        }
+
+        fn unbounded<T>(&self) -> (BoxUnboundedSender<T>, BoxAsyncReceiver<T>)
+        where
+            T: Send + 'static,
+        {
+            let (tx, rx) = flume::unbounded();
+            (Box::new(tx), Box::new(rx))
+        }
+    }
+}
+
+#[cfg(test)]
+mod multi_producer_impl_tests {
+    use crate::{
+        error::{SendError, SendErrorWithoutFull},
+        multi_producer::{BlockingBoundedSender, BoundedSender, UnboundedSender},
+    };
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = flume::bounded(1);
+            let sender: Box<dyn BoundedSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1).await, Ok(()));
+            assert_eq!(sender.try_send(2), Err(SendError::Full(2)));
+            assert!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), sender.send(2))
+                    .await
+                    .is_err()
+            );
+            assert_eq!(rx.recv_async().await, Ok(1));
+            drop(rx);
+            assert_eq!(
+                sender.send(3).await,
+                Err(SendErrorWithoutFull::Disconnected(3))
+            );
+            assert_eq!(sender.try_send(3), Err(SendError::Disconnected(3)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded() {
+        {
+            let (tx, rx) = flume::unbounded();
+            let sender: Box<dyn UnboundedSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Ok(()));
+            assert_eq!(rx.recv_async().await, Ok(1));
+            assert_eq!(rx.recv_async().await, Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendErrorWithoutFull::Disconnected(3)));
+        }
+    }
+
+    #[test]
+    fn test_blocking_with_bounded() {
+        {
+            let (tx, rx) = flume::bounded(1);
+            let sender: Box<dyn BlockingBoundedSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.try_send(2), Err(SendError::Full(2)));
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                assert_eq!(rx.recv(), Ok(1));
+                rx
+            });
+            assert_eq!(sender.send(2), Ok(()));
+            let rx = handle.join().unwrap();
+            assert_eq!(rx.recv(), Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendErrorWithoutFull::Disconnected(3)));
+            assert_eq!(sender.try_send(3), Err(SendError::Disconnected(3)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod generic_impl_tests {
+    use crate::{
+        error::SendError,
+        generic::{CloneableSender, Sender},
+    };
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = flume::bounded(1);
+            let sender: Box<dyn Sender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Err(SendError::Full(2)));
+            assert_eq!(rx.recv_async().await, Ok(1));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendError::Disconnected(3)));
+        }
+        {
+            let (tx, rx) = flume::bounded(1);
+            let sender: Box<dyn CloneableSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Err(SendError::Full(2)));
+            assert_eq!(rx.recv_async().await, Ok(1));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendError::Disconnected(3)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded() {
+        {
+            let (tx, rx) = flume::unbounded();
+            let sender: Box<dyn Sender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Ok(()));
+            assert_eq!(rx.recv_async().await, Ok(1));
+            assert_eq!(rx.recv_async().await, Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendError::Disconnected(3)));
+        }
+        {
+            let (tx, rx) = flume::unbounded();
+            let sender: Box<dyn CloneableSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Ok(()));
+            assert_eq!(rx.recv_async().await, Ok(1));
+            assert_eq!(rx.recv_async().await, Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendError::Disconnected(3)));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "factory_flume"))]
+mod factory_impl_tests {
+    use super::*;
+
+    use crate::factory::factory_tests;
+
+    #[tokio::test]
+    async fn test_with_factory() {
+        factory_tests::test_factory(FlumeFactory).await;
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be greater than zero")]
+    fn test_with_zero_capacity() {
+        factory_tests::test_zero_capacity(FlumeFactory);
+    }
+}
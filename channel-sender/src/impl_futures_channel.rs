@@ -0,0 +1,444 @@
+use core::{
+    future::poll_fn,
+    task::{Context, Poll},
+};
+use std::sync::{Mutex, MutexGuard};
+
+use futures_channel::mpsc::TrySendError;
+pub use futures_channel::{
+    mpsc::{Sender as FuturesMpscSender, UnboundedSender as FuturesMpscUnboundedSender},
+    oneshot::Sender as FuturesOneshotSender,
+};
+
+//
+// futures-channel needs `&mut self` to send on a bounded channel, so the bounded traits are
+// implemented on this wrapper rather than on `FuturesMpscSender` itself. Every futures-channel
+// sender gets one slot of its own on top of the buffer; once that is taken `try_send` reports
+// `Full`. Cloning the wrapper clones the inner sender, so each clone brings its own slot.
+pub struct FuturesMpscSenderWrapper<T>(pub Mutex<FuturesMpscSender<T>>);
+
+impl<T> FuturesMpscSenderWrapper<T> {
+    fn lock(&self) -> MutexGuard<'_, FuturesMpscSender<T>> {
+        self.0
+            .lock()
+            .expect("FuturesMpscSenderWrapper lock poisoned")
+    }
+}
+
+impl<T> From<FuturesMpscSender<T>> for FuturesMpscSenderWrapper<T> {
+    fn from(sender: FuturesMpscSender<T>) -> Self {
+        Self(Mutex::new(sender))
+    }
+}
+
+impl<T> Clone for FuturesMpscSenderWrapper<T> {
+    fn clone(&self) -> Self {
+        Self::from(self.lock().clone())
+    }
+}
+
+//
+mod multi_producer_impl {
+    use super::*;
+
+    use core::future::Future;
+    use std::time::Instant;
+
+    use crate::{
+        error::{SendError, SendErrorWithoutFull, SendTimeoutError},
+        multi_producer::{NativeBoundedSender, UnboundedSender},
+        timer::{timeout_at, Timer},
+    };
+
+    // futures-channel parks one waker per sender, so a send that finds the shared sender full goes
+    // through a clone of its own, or concurrent sends would keep replacing each other's waker. A
+    // fresh clone always has its own slot, so the value is queued right away; the send then waits
+    // until that clone could send again, which keeps a sending task from running ahead of the
+    // receiver. Cancelling it during that wait doesn't take the value back.
+    async fn send_on_clone<T>(
+        wrapper: &FuturesMpscSenderWrapper<T>,
+        t: T,
+        deadline: Option<(Instant, &dyn Timer)>,
+    ) -> Result<(), SendError<T>> {
+        let t = match NativeBoundedSender::try_send(wrapper, t) {
+            Err(SendError::Full(t)) => t,
+            result => return result,
+        };
+        let mut sender = wrapper.lock().clone();
+        FuturesMpscSender::try_send(&mut sender, t).map_err(SendError::from)?;
+
+        let ready = poll_fn(|cx| FuturesMpscSender::poll_ready(&mut sender, cx));
+        match deadline {
+            Some((deadline, timer)) => {
+                let _ = timeout_at(ready, deadline, timer).await;
+            }
+            None => {
+                let _ = ready.await;
+            }
+        }
+        Ok(())
+    }
+
+    impl<T> NativeBoundedSender<T> for FuturesMpscSenderWrapper<T> {
+        async fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>
+        where
+            T: Send,
+        {
+            send_on_clone(self, t, None).await.map_err(|err| match err {
+                SendError::Full(t) => SendErrorWithoutFull::UnreachableFull(t),
+                SendError::Closed(t) => SendErrorWithoutFull::Closed(t),
+                SendError::Disconnected(t) => SendErrorWithoutFull::Disconnected(t),
+            })
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            FuturesMpscSender::try_send(&mut self.lock(), t).map_err(Into::into)
+        }
+
+        // The value is queued as soon as there is a clone to carry it, so this only times out the
+        // wait that follows and never hands the value back for `Timeout`.
+        #[allow(clippy::manual_async_fn)]
+        fn send_deadline<'a>(
+            &'a self,
+            t: T,
+            deadline: Instant,
+            timer: &'a dyn Timer,
+        ) -> impl Future<Output = Result<(), SendTimeoutError<T>>> + Send + 'a
+        where
+            T: Send + 'a,
+            Self: Sync,
+        {
+            async move {
+                send_on_clone(self, t, Some((deadline, timer)))
+                    .await
+                    .map_err(|err| match err {
+                        SendError::Full(t) => SendTimeoutError::Timeout(Some(t)),
+                        SendError::Closed(t) => SendTimeoutError::Closed(t),
+                        SendError::Disconnected(t) => SendTimeoutError::Disconnected(t),
+                    })
+            }
+        }
+    }
+
+    impl<T> UnboundedSender<T> for FuturesMpscUnboundedSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            FuturesMpscUnboundedSender::unbounded_send(self, t)
+                .map_err(|err| SendErrorWithoutFull::Disconnected(err.into_inner()))
+        }
+    }
+}
+
+//
+mod one_shot_impl {
+    use super::*;
+
+    use crate::{
+        error::SendErrorWithoutFull,
+        one_shot::{BoxSender, Sender},
+    };
+
+    impl<T> Sender<T> for FuturesOneshotSender<T> {
+        fn send(self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            FuturesOneshotSender::send(self, t).map_err(SendErrorWithoutFull::Closed)
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(FuturesOneshotSender::is_canceled(self))
+        }
+
+        fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+            FuturesOneshotSender::poll_canceled(self, cx)
+        }
+    }
+
+    impl<T> BoxSender<T> for FuturesOneshotSender<T> {
+        fn send(self: Box<Self>, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            FuturesOneshotSender::send(*self, t).map_err(SendErrorWithoutFull::Closed)
+        }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(FuturesOneshotSender::is_canceled(self))
+        }
+
+        fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+            FuturesOneshotSender::poll_canceled(self, cx)
+        }
+    }
+}
+
+//
+mod generic_impl {
+    use super::*;
+
+    use crate::{
+        error::SendError,
+        generic::{CloneableSender, Sender},
+    };
+
+    impl<T> Sender<T> for FuturesMpscSenderWrapper<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            FuturesMpscSender::try_send(&mut self.lock(), t).map_err(Into::into)
+        }
+    }
+
+    impl<T> CloneableSender<T> for FuturesMpscSenderWrapper<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            FuturesMpscSender::try_send(&mut self.lock(), t).map_err(Into::into)
+        }
+    }
+
+    impl<T> Sender<T> for FuturesMpscUnboundedSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            FuturesMpscUnboundedSender::unbounded_send(self, t).map_err(Into::into)
+        }
+    }
+
+    impl<T> CloneableSender<T> for FuturesMpscUnboundedSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            FuturesMpscUnboundedSender::unbounded_send(self, t).map_err(Into::into)
+        }
+    }
+}
+
+//
+mod introspect_impl {
+    use super::*;
+
+    use core::any::Any;
+
+    use crate::introspect::Introspect;
+
+    impl<T> Introspect for FuturesMpscSenderWrapper<T> {
+        fn is_closed(&self) -> Option<bool> {
+            Some(FuturesMpscSender::is_closed(&self.lock()))
+        }
+
+        fn as_any(&self) -> Option<&dyn Any>
+        where
+            Self: 'static,
+        {
+            Some(self)
+        }
+
+        fn same_channel(&self, other: &(dyn Introspect + 'static)) -> Option<bool>
+        where
+            Self: 'static,
+        {
+            Some(
+                other
+                    .as_any()
+                    .and_then(|other| other.downcast_ref::<Self>())
+                    .is_some_and(|other| {
+                        FuturesMpscSender::same_receiver(&self.lock(), &other.lock())
+                    }),
+            )
+        }
+    }
+}
+
+//
+mod error_convert {
+    use super::*;
+
+    use crate::error::SendError;
+
+    impl<T> From<TrySendError<T>> for SendError<T> {
+        fn from(err: TrySendError<T>) -> Self {
+            if err.is_full() {
+                Self::Full(err.into_inner())
+            } else {
+                Self::Disconnected(err.into_inner())
+            }
+        }
+    }
+}
+
+//
+#[cfg(feature = "factory_futures_channel")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FuturesChannelFactory;
+
+#[cfg(feature = "factory_futures_channel")]
+mod factory_impl {
+    use super::*;
+
+    use channel_receiver::impl_futures_channel::FuturesOneshotReceiverWrapper;
+
+    use crate::factory::{
+        BoxAsyncReceiver, BoxBoundedSender, BoxOneshotReceiver, BoxOneshotSender,
+        BoxUnboundedSender, ChannelFactory,
+    };
+
+    impl ChannelFactory for FuturesChannelFactory {
+        fn bounded<T>(&self, capacity: usize) -> (BoxBoundedSender<T>, BoxAsyncReceiver<T>)
+        where
+            T: Send + 'static,
+        {
+            assert!(capacity > 0, "capacity must be greater than zero");
+            let (tx, rx) = futures_channel::mpsc::channel(capacity);
+            (Box::new(FuturesMpscSenderWrapper::from(tx)), Box::new(rx))
+        }
+
+        fn unbounded<T>(&self) -> (BoxUnboundedSender<T>, BoxAsyncReceiver<T>)
+        where
+            T: Send + 'static,
+        {
+            let (tx, rx) = futures_channel::mpsc::unbounded();
+            (Box::new(tx), Box::new(rx))
+        }
+
+        fn oneshot<T>(&self) -> Option<(BoxOneshotSender<T>, BoxOneshotReceiver<T>)>
+        where
+            T: Send + 'static,
+        {
+            let (tx, rx) = futures_channel::oneshot::channel();
+            Some((Box::new(tx), Box::new(FuturesOneshotReceiverWrapper(rx))))
+        }
+    }
+}
+
+#[cfg(test)]
+mod multi_producer_impl_tests {
+    use core::time::Duration;
+
+    use futures_util::StreamExt as _;
+
+    use super::*;
+    use crate::{
+        error::{SendError, SendErrorWithoutFull},
+        multi_producer::{BoundedSender, UnboundedSender},
+        timer::{ManualTimer, Timer as _},
+    };
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, mut rx) = futures_channel::mpsc::channel(1);
+            let sender: Box<dyn BoundedSender<usize>> =
+                Box::new(FuturesMpscSenderWrapper::from(tx));
+            assert_eq!(sender.send(1).await, Ok(()));
+            // One slot in the buffer, plus the one every sender has to itself.
+            assert_eq!(sender.try_send(2), Ok(()));
+            assert_eq!(sender.try_send(3), Err(SendError::Full(3)));
+            // Queued through a clone straight away, then held back until the receiver catches up.
+            assert!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), sender.send(3))
+                    .await
+                    .is_err()
+            );
+            let timer = ManualTimer::new();
+            let deadline = timer.now() + Duration::from_secs(1);
+            let send = sender.send_deadline(4, deadline, &timer);
+            timer.advance(Duration::from_secs(1));
+            assert_eq!(send.await, Ok(()));
+
+            for i in 1..=4 {
+                assert_eq!(rx.next().await, Some(i));
+            }
+            assert_eq!(sender.send(5).await, Ok(()));
+            assert_eq!(rx.next().await, Some(5));
+
+            drop(rx);
+            assert_eq!(sender.is_closed(), Some(true));
+            assert_eq!(sender.send(4).await, Err(SendErrorWithoutFull::Closed(4)));
+            assert_eq!(sender.try_send(5), Err(SendError::Closed(5)));
+        }
+        {
+            // Concurrent sends on one shared sender each wait on their own clone.
+            let (tx, mut rx) = futures_channel::mpsc::channel(0);
+            let sender: Box<dyn BoundedSender<usize>> =
+                Box::new(FuturesMpscSenderWrapper::from(tx));
+            let handles = (0..2)
+                .map(|_| {
+                    let sender = sender.clone();
+                    tokio::spawn(async move {
+                        for i in 0..100 {
+                            assert_eq!(sender.send(i).await, Ok(()));
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            drop(sender);
+            let mut n = 0;
+            while rx.next().await.is_some() {
+                n += 1;
+            }
+            assert_eq!(n, 200);
+            for handle in handles {
+                handle.await.unwrap();
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded_channel() {
+        {
+            let (tx, mut rx) = futures_channel::mpsc::unbounded();
+            let sender: Box<dyn UnboundedSender<usize> + Send + Sync> = Box::new(tx);
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.len(), Some(1));
+            assert_eq!(sender.same_channel(&sender.clone()), Some(true));
+            assert_eq!(rx.next().await, Some(1));
+            drop(rx);
+            assert_eq!(sender.send(2), Err(SendErrorWithoutFull::Closed(2)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod one_shot_impl_tests {
+    use crate::one_shot::{BoxSender, Sender};
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, rx) = futures_channel::oneshot::channel();
+            assert_eq!(Sender::send(tx, 1), Ok(()));
+            assert_eq!(rx.await, Ok(1));
+
+            let (tx, rx) = futures_channel::oneshot::channel::<usize>();
+            let mut sender: Box<dyn BoxSender<usize> + Send> = Box::new(tx);
+            assert_eq!(sender.is_closed(), Some(false));
+            drop(rx);
+            sender.closed().unwrap().await;
+            assert!(sender.send(1).is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod generic_impl_tests {
+    use futures_util::StreamExt as _;
+
+    use crate::{error::SendError, generic::CloneableSender};
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, mut rx) = futures_channel::mpsc::unbounded();
+            let sender: Box<dyn CloneableSender<usize>> = Box::new(tx);
+            assert_eq!(sender.clone().send(1), Ok(()));
+            assert_eq!(rx.next().await, Some(1));
+            drop(rx);
+            assert_eq!(sender.send(2), Err(SendError::Closed(2)));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "factory_futures_channel"))]
+mod factory_impl_tests {
+    use super::*;
+
+    use crate::factory::factory_tests;
+
+    #[tokio::test]
+    async fn test_with_factory() {
+        factory_tests::test_factory(FuturesChannelFactory).await;
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be greater than zero")]
+    fn test_with_zero_capacity() {
+        factory_tests::test_zero_capacity(FuturesChannelFactory);
+    }
+}
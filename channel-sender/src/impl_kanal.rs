@@ -0,0 +1,392 @@
+use core::time::Duration;
+
+// kanal 0.1 has no oneshot channel, only the bounded and unbounded flavours.
+pub use kanal::{AsyncSender as KanalAsyncSender, Sender as KanalSender};
+use kanal::{SendError as SendErrorInner, SendErrorTimeout as SendErrorTimeoutInner};
+
+use crate::error::{SendError, SendErrorWithoutFull};
+
+// kanal drops the value whenever one of its plain `send`s fails, so everything below goes through
+// the `Option` based variants, which leave it in place for the error.
+fn try_send<T>(sender: &KanalSender<T>, t: T) -> Result<(), SendError<T>> {
+    let mut t = Some(t);
+    match KanalSender::try_send_option(sender, &mut t) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(SendError::Full(t.expect("unsent value"))),
+        Err(SendErrorInner::Closed) => Err(SendError::Closed(t.expect("unsent value"))),
+        Err(SendErrorInner::ReceiveClosed) => {
+            Err(SendError::Disconnected(t.expect("unsent value")))
+        }
+    }
+}
+
+// `send_option_timeout` is the only blocking send that hands the value back when it fails, so an
+// open-ended wait is a loop of timed ones, each slice parked on kanal's own wakeup.
+const BLOCKING_SEND_SLICE: Duration = Duration::from_secs(60);
+
+fn send_blocking<T>(sender: &KanalSender<T>, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+    let mut t = Some(t);
+    loop {
+        match KanalSender::send_option_timeout(sender, &mut t, BLOCKING_SEND_SLICE) {
+            Ok(()) => return Ok(()),
+            Err(SendErrorTimeoutInner::Timeout) => continue,
+            Err(SendErrorTimeoutInner::Closed) => {
+                return Err(SendErrorWithoutFull::Closed(t.expect("unsent value")))
+            }
+            Err(SendErrorTimeoutInner::ReceiveClosed) => {
+                return Err(SendErrorWithoutFull::Disconnected(t.expect("unsent value")))
+            }
+        }
+    }
+}
+
+// kanal's async send drops the value if the channel closes while it waits for room, so `try_send`
+// goes first to hand the value back for a channel that is already closed. Only a close that races
+// the wait can lose it, and then the send reports success, as if the value had been queued just
+// before the receivers went away.
+async fn send_async<T>(sender: &KanalAsyncSender<T>, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+    let t = match try_send(sender.as_sync(), t) {
+        Ok(()) => return Ok(()),
+        Err(SendError::Full(t)) => t,
+        Err(SendError::Closed(t)) => return Err(SendErrorWithoutFull::Closed(t)),
+        Err(SendError::Disconnected(t)) => return Err(SendErrorWithoutFull::Disconnected(t)),
+    };
+    let _ = KanalAsyncSender::send(sender, t).await;
+    Ok(())
+}
+
+fn send_unbounded<T>(sender: &KanalSender<T>, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+    debug_assert!(
+        !sender.is_bounded(),
+        "Unbounded channels are never full. Make sure you are using `kanal::unbounded`."
+    );
+
+    match try_send(sender, t) {
+        Ok(_) => Ok(()),
+        Err(err) => match err {
+            SendError::Full(v) => Err(SendErrorWithoutFull::UnreachableFull(v)),
+            SendError::Closed(v) => Err(SendErrorWithoutFull::Closed(v)),
+            SendError::Disconnected(v) => Err(SendErrorWithoutFull::Disconnected(v)),
+        },
+    }
+}
+
+//
+mod multi_producer_impl {
+    use super::*;
+
+    use crate::multi_producer::{BlockingBoundedSender, NativeBoundedSender, UnboundedSender};
+
+    impl<T> NativeBoundedSender<T> for KanalSender<T> {
+        async fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>
+        where
+            T: Send,
+        {
+            send_async(self.as_async(), t).await
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            try_send(self, t)
+        }
+    }
+
+    impl<T> NativeBoundedSender<T> for KanalAsyncSender<T> {
+        async fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>
+        where
+            T: Send,
+        {
+            send_async(self, t).await
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            try_send(self.as_sync(), t)
+        }
+    }
+
+    impl<T> BlockingBoundedSender<T> for KanalSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            send_blocking(self, t)
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            try_send(self, t)
+        }
+    }
+
+    impl<T> BlockingBoundedSender<T> for KanalAsyncSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            send_blocking(self.as_sync(), t)
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            try_send(self.as_sync(), t)
+        }
+    }
+
+    impl<T> UnboundedSender<T> for KanalSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            send_unbounded(self, t)
+        }
+    }
+
+    impl<T> UnboundedSender<T> for KanalAsyncSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            send_unbounded(self.as_sync(), t)
+        }
+    }
+}
+
+//
+mod generic_impl {
+    use super::*;
+
+    use crate::generic::{CloneableSender, Sender};
+
+    impl<T> Sender<T> for KanalSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            try_send(self, t)
+        }
+    }
+
+    impl<T> CloneableSender<T> for KanalSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            try_send(self, t)
+        }
+    }
+
+    impl<T> Sender<T> for KanalAsyncSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            try_send(self.as_sync(), t)
+        }
+    }
+
+    impl<T> CloneableSender<T> for KanalAsyncSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            try_send(self.as_sync(), t)
+        }
+    }
+}
+
+//
+#[cfg(feature = "factory_kanal")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KanalFactory;
+
+#[cfg(feature = "factory_kanal")]
+mod factory_impl {
+    use super::*;
+
+    use crate::factory::{BoxAsyncReceiver, BoxBoundedSender, BoxUnboundedSender, ChannelFactory};
+
+    impl ChannelFactory for KanalFactory {
+        fn bounded<T>(&self, capacity: usize) -> (BoxBoundedSender<T>, BoxAsyncReceiver<T>)
+        where
+            T: Send + 'static,
+        {
+            assert!(capacity > 0, "capacity must be greater than zero");
+            let (tx, rx) = kanal::bounded_async(capacity);
+            (Box::new(tx), Box::new(rx))
+        }
+
+        fn unbounded<T>(&self) -> (BoxUnboundedSender<T>, BoxAsyncReceiver<T>)
+        where
+            T: Send + 'static,
+        {
+            let (tx, rx) = kanal::unbounded_async();
+            (Box::new(tx), Box::new(rx))
+        }
+    }
+}
+
+#[cfg(test)]
+mod multi_producer_impl_tests {
+    use crate::{
+        error::{SendError, SendErrorWithoutFull},
+        multi_producer::{BlockingBoundedSender, BoundedSender, UnboundedSender},
+    };
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = kanal::bounded_async(1);
+            let sender: Box<dyn BoundedSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1).await, Ok(()));
+            assert_eq!(sender.try_send(2), Err(SendError::Full(2)));
+            assert!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), sender.send(2))
+                    .await
+                    .is_err()
+            );
+            assert_eq!(rx.recv().await, Ok(1));
+            drop(rx);
+            assert_eq!(
+                sender.send(3).await,
+                Err(SendErrorWithoutFull::Disconnected(3))
+            );
+            assert_eq!(sender.try_send(3), Err(SendError::Disconnected(3)));
+        }
+        {
+            let (tx, rx) = kanal::bounded(1);
+            let sender: Box<dyn BoundedSender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1).await, Ok(()));
+            let handle = tokio::task::spawn_blocking(move || {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                assert_eq!(rx.recv(), Ok(1));
+                rx
+            });
+            assert_eq!(sender.send(2).await, Ok(()));
+            let rx = handle.await.unwrap();
+            assert_eq!(rx.recv(), Ok(2));
+        }
+        {
+            let (tx, rx) = kanal::bounded_async::<usize>(1);
+            let sender: Box<dyn BoundedSender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1).await, Ok(()));
+            let handle = tokio::spawn(async move {
+                tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+                drop(rx);
+            });
+            // Closing under a waiting send drops the value and reports it as sent.
+            assert_eq!(sender.send(2).await, Ok(()));
+            assert_eq!(
+                sender.send(3).await,
+                Err(SendErrorWithoutFull::Disconnected(3))
+            );
+            handle.await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded() {
+        {
+            let (tx, rx) = kanal::unbounded_async();
+            let sender: Box<dyn UnboundedSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Ok(()));
+            assert_eq!(rx.recv().await, Ok(1));
+            assert_eq!(rx.recv().await, Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendErrorWithoutFull::Disconnected(3)));
+        }
+    }
+
+    #[test]
+    fn test_blocking_with_bounded() {
+        {
+            let (tx, rx) = kanal::bounded(1);
+            let sender: Box<dyn BlockingBoundedSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.try_send(2), Err(SendError::Full(2)));
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                assert_eq!(rx.recv(), Ok(1));
+                rx
+            });
+            assert_eq!(sender.send(2), Ok(()));
+            let rx = handle.join().unwrap();
+            assert_eq!(rx.recv(), Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendErrorWithoutFull::Disconnected(3)));
+            assert_eq!(sender.try_send(3), Err(SendError::Disconnected(3)));
+        }
+        {
+            let (tx, rx) = kanal::bounded::<usize>(1);
+            let sender: Box<dyn BlockingBoundedSender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1), Ok(()));
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                drop(rx);
+            });
+            assert_eq!(sender.send(2), Err(SendErrorWithoutFull::Disconnected(2)));
+            handle.join().unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod generic_impl_tests {
+    use crate::{
+        error::SendError,
+        generic::{CloneableSender, Sender},
+    };
+
+    #[tokio::test]
+    async fn test_with_bounded() {
+        {
+            let (tx, rx) = kanal::bounded(1);
+            let sender: Box<dyn Sender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Err(SendError::Full(2)));
+            assert_eq!(rx.recv(), Ok(1));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendError::Disconnected(3)));
+        }
+        {
+            let (tx, rx) = kanal::bounded_async(1);
+            let sender: Box<dyn CloneableSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Err(SendError::Full(2)));
+            assert_eq!(rx.recv().await, Ok(1));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendError::Disconnected(3)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded() {
+        {
+            let (tx, rx) = kanal::unbounded_async();
+            let sender: Box<dyn Sender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Ok(()));
+            assert_eq!(rx.recv().await, Ok(1));
+            assert_eq!(rx.recv().await, Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendError::Disconnected(3)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod introspect_impl_tests {
+    use crate::introspect::Introspect;
+
+    #[test]
+    fn test_with_bounded() {
+        let (tx, rx) = kanal::bounded_async::<usize>(2);
+        let tx2 = tx.clone();
+        assert_eq!(tx.try_send(1), Ok(true));
+        assert_eq!(Introspect::len(&tx), Some(1));
+        assert_eq!(Introspect::capacity(&tx), Some(1));
+        assert_eq!(Introspect::max_capacity(&tx), Some(2));
+        assert_eq!(Introspect::sender_count(&tx), Some(2));
+        assert_eq!(Introspect::receiver_count(&tx), Some(1));
+        assert_eq!(Introspect::is_closed(&tx), Some(false));
+        drop(tx2);
+        drop(rx);
+        assert_eq!(Introspect::sender_count(&tx), Some(1));
+        assert_eq!(Introspect::is_closed(&tx), Some(true));
+    }
+}
+
+#[cfg(all(test, feature = "factory_kanal"))]
+mod factory_impl_tests {
+    use super::*;
+
+    use crate::factory::factory_tests;
+
+    #[tokio::test]
+    async fn test_with_factory() {
+        factory_tests::test_factory(KanalFactory).await;
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be greater than zero")]
+    fn test_with_zero_capacity() {
+        factory_tests::test_zero_capacity(KanalFactory);
+    }
+}
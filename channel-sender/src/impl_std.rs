@@ -5,13 +5,27 @@ pub use std::sync::mpsc::{Sender as StdMpscSender, SyncSender as StdMpscSyncSend
 mod multi_producer_impl {
     use super::*;
 
-    use crate::{error::SendErrorWithoutFull, multi_producer::UnboundedSender};
+    use crate::{
+        error::{SendError, SendErrorWithoutFull},
+        multi_producer::{BlockingBoundedSender, UnboundedSender},
+    };
 
     impl<T> UnboundedSender<T> for StdMpscSender<T> {
         fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
             StdMpscSender::send(self, t).map_err(|err| SendErrorWithoutFull::Disconnected(err.0))
         }
     }
+
+    impl<T> BlockingBoundedSender<T> for StdMpscSyncSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            StdMpscSyncSender::send(self, t)
+                .map_err(|err| SendErrorWithoutFull::Disconnected(err.0))
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            StdMpscSyncSender::try_send(self, t).map_err(Into::into)
+        }
+    }
 }
 
 //
@@ -69,7 +83,10 @@ mod error_convert {
 
 #[cfg(test)]
 mod multi_producer_impl_tests {
-    use crate::{error::SendErrorWithoutFull, multi_producer::UnboundedSender};
+    use crate::{
+        error::{SendBatchError, SendError, SendErrorWithoutFull},
+        multi_producer::{BlockingBoundedSender, UnboundedSender},
+    };
 
     #[test]
     fn test_with_channel() {
@@ -85,6 +102,53 @@ mod multi_producer_impl_tests {
             assert_eq!(sender.send(3), Err(SendErrorWithoutFull::Disconnected(3)));
         }
     }
+
+    #[test]
+    fn test_with_sync_channel() {
+        {
+            let (tx, rx) = std::sync::mpsc::sync_channel(1);
+            let sender: Box<dyn BlockingBoundedSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.try_send(2), Err(SendError::Full(2)));
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                assert_eq!(rx.recv(), Ok(1));
+                rx
+            });
+            assert_eq!(sender.send(2), Ok(()));
+            let rx = handle.join().unwrap();
+            assert_eq!(rx.recv(), Ok(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendErrorWithoutFull::Disconnected(3)));
+            assert_eq!(sender.try_send(3), Err(SendError::Disconnected(3)));
+        }
+    }
+
+    #[test]
+    fn test_batch_with_sync_channel() {
+        {
+            let (tx, rx) = std::sync::mpsc::sync_channel(2);
+            let sender: Box<dyn BlockingBoundedSender<usize>> = Box::new(tx);
+            assert_eq!(
+                sender.try_send_batch(vec![1, 2, 3]),
+                Err(SendBatchError::Full(vec![3]))
+            );
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                for i in 1..=4 {
+                    assert_eq!(rx.recv(), Ok(i));
+                }
+                rx
+            });
+            assert_eq!(sender.send_all(vec![3, 4]), Ok(()));
+            drop(handle.join().unwrap());
+            assert_eq!(
+                sender.send_all(vec![5, 6]),
+                Err(SendBatchError::Disconnected(vec![5, 6]))
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -142,3 +206,24 @@ mod generic_impl_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod introspect_impl_tests {
+    use crate::multi_producer::{BlockingBoundedSender, UnboundedSender};
+
+    #[test]
+    fn test_with_channel() {
+        {
+            let (tx, _rx) = std::sync::mpsc::channel::<usize>();
+            let sender: Box<dyn UnboundedSender<usize>> = Box::new(tx);
+            assert_eq!(sender.len(), None);
+            assert_eq!(sender.is_empty(), None);
+            assert_eq!(sender.is_closed(), None);
+            assert_eq!(sender.sender_count(), None);
+
+            let (tx, _rx) = std::sync::mpsc::sync_channel::<usize>(1);
+            let sender: Box<dyn BlockingBoundedSender<usize>> = Box::new(tx);
+            assert_eq!(sender.max_capacity(), None);
+        }
+    }
+}
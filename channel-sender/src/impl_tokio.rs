@@ -1,7 +1,16 @@
+use core::future::Future;
+use std::time::Instant;
+
 use tokio::sync::mpsc::error::TrySendError;
 pub use tokio::sync::{
-    mpsc::{Sender as TokioMpscSender, UnboundedSender as TokioMpscUnboundedSender},
+    broadcast::Sender as TokioBroadcastSender,
+    mpsc::{
+        Permit as TokioMpscPermit, Sender as TokioMpscSender,
+        UnboundedSender as TokioMpscUnboundedSender, WeakSender as TokioMpscWeakSender,
+        WeakUnboundedSender as TokioMpscWeakUnboundedSender,
+    },
     oneshot::Sender as TokioOneshotSender,
+    watch::Sender as TokioWatchSender,
 };
 
 //
@@ -9,12 +18,15 @@ mod multi_producer_impl {
     use super::*;
 
     use crate::{
-        error::{SendError, SendErrorWithoutFull},
-        multi_producer::{BoundedSender, UnboundedSender},
+        error::{SendError, SendErrorWithoutFull, SendTimeoutError},
+        multi_producer::{
+            BlockingBoundedSender, BoundedSender, BoxPermit, BoxWeakSender, Closed,
+            NativeBoundedSender, Permit, ReservableBoundedSender, UnboundedSender, WeakSender,
+        },
+        timer::{timeout_at, Timer},
     };
 
-    #[async_trait::async_trait]
-    impl<T> BoundedSender<T> for TokioMpscSender<T> {
+    impl<T> NativeBoundedSender<T> for TokioMpscSender<T> {
         async fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>
         where
             T: Send,
@@ -27,6 +39,78 @@ mod multi_producer_impl {
         fn try_send(&self, t: T) -> Result<(), SendError<T>> {
             TokioMpscSender::try_send(self, t).map_err(Into::into)
         }
+
+        fn closed(&self) -> Option<Closed<'_>>
+        where
+            T: Send,
+        {
+            Some(Box::pin(TokioMpscSender::closed(self)))
+        }
+
+        fn downgrade(&self) -> Option<BoxWeakSender<dyn BoundedSender<T> + Send + Sync>>
+        where
+            T: Send + 'static,
+        {
+            Some(Box::new(TokioMpscSender::downgrade(self)))
+        }
+
+        // Waits on a permit rather than the send itself, so the value is still ours on timeout.
+        #[allow(clippy::manual_async_fn)]
+        fn send_deadline<'a>(
+            &'a self,
+            t: T,
+            deadline: Instant,
+            timer: &'a dyn Timer,
+        ) -> impl Future<Output = Result<(), SendTimeoutError<T>>> + Send + 'a
+        where
+            T: Send + 'a,
+            Self: Sync,
+        {
+            async move {
+                match timeout_at(TokioMpscSender::reserve(self), deadline, timer).await {
+                    Some(Ok(permit)) => {
+                        permit.send(t);
+                        Ok(())
+                    }
+                    Some(Err(_)) => Err(SendTimeoutError::Closed(t)),
+                    None => Err(SendTimeoutError::Timeout(Some(t))),
+                }
+            }
+        }
+    }
+
+    impl<T> BlockingBoundedSender<T> for TokioMpscSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            TokioMpscSender::blocking_send(self, t)
+                .map_err(|err| SendErrorWithoutFull::Closed(err.0))
+        }
+
+        fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+            TokioMpscSender::try_send(self, t).map_err(Into::into)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<T: Send> ReservableBoundedSender<T> for TokioMpscSender<T> {
+        async fn reserve(&self) -> Result<BoxPermit<'_, T>, SendErrorWithoutFull<()>> {
+            match TokioMpscSender::reserve(self).await {
+                Ok(permit) => Ok(Box::new(permit)),
+                Err(_) => Err(SendErrorWithoutFull::Closed(())),
+            }
+        }
+
+        fn try_reserve(&self) -> Result<BoxPermit<'_, T>, SendError<()>> {
+            match TokioMpscSender::try_reserve(self) {
+                Ok(permit) => Ok(Box::new(permit)),
+                Err(err) => Err(err.into()),
+            }
+        }
+    }
+
+    impl<T> Permit<T> for TokioMpscPermit<'_, T> {
+        fn send(self: Box<Self>, t: T) {
+            TokioMpscPermit::send(*self, t)
+        }
     }
 
     impl<T> UnboundedSender<T> for TokioMpscUnboundedSender<T> {
@@ -34,6 +118,34 @@ mod multi_producer_impl {
             TokioMpscUnboundedSender::send(self, t)
                 .map_err(|err| SendErrorWithoutFull::Closed(err.0))
         }
+
+        fn closed(&self) -> Option<Closed<'_>>
+        where
+            T: Send,
+        {
+            Some(Box::pin(TokioMpscUnboundedSender::closed(self)))
+        }
+
+        fn downgrade(&self) -> Option<BoxWeakSender<dyn UnboundedSender<T> + Send + Sync>>
+        where
+            T: Send + 'static,
+        {
+            Some(Box::new(TokioMpscUnboundedSender::downgrade(self)))
+        }
+    }
+
+    impl<T: Send + 'static> WeakSender<dyn BoundedSender<T> + Send + Sync> for TokioMpscWeakSender<T> {
+        fn upgrade(&self) -> Option<Box<dyn BoundedSender<T> + Send + Sync>> {
+            TokioMpscWeakSender::upgrade(self).map(|sender| Box::new(sender) as _)
+        }
+    }
+
+    impl<T: Send + 'static> WeakSender<dyn UnboundedSender<T> + Send + Sync>
+        for TokioMpscWeakUnboundedSender<T>
+    {
+        fn upgrade(&self) -> Option<Box<dyn UnboundedSender<T> + Send + Sync>> {
+            TokioMpscWeakUnboundedSender::upgrade(self).map(|sender| Box::new(sender) as _)
+        }
     }
 }
 
@@ -41,6 +153,8 @@ mod multi_producer_impl {
 mod one_shot_impl {
     use super::*;
 
+    use core::task::{Context, Poll};
+
     use crate::{
         error::SendErrorWithoutFull,
         one_shot::{BoxSender, Sender},
@@ -50,12 +164,28 @@ mod one_shot_impl {
         fn send(self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
             TokioOneshotSender::send(self, t).map_err(|t| SendErrorWithoutFull::Closed(t))
         }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(TokioOneshotSender::is_closed(self))
+        }
+
+        fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+            TokioOneshotSender::poll_closed(self, cx)
+        }
     }
 
     impl<T> BoxSender<T> for TokioOneshotSender<T> {
         fn send(self: Box<Self>, t: T) -> Result<(), SendErrorWithoutFull<T>> {
             TokioOneshotSender::send(*self, t).map_err(|t| SendErrorWithoutFull::Closed(t))
         }
+
+        fn is_closed(&self) -> Option<bool> {
+            Some(TokioOneshotSender::is_closed(self))
+        }
+
+        fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+            TokioOneshotSender::poll_closed(self, cx)
+        }
     }
 }
 
@@ -91,6 +221,62 @@ mod generic_impl {
             TokioMpscUnboundedSender::send(self, t).map_err(|err| SendError::Closed(err.0))
         }
     }
+
+    impl<T> Sender<T> for TokioBroadcastSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            TokioBroadcastSender::send(self, t)
+                .map(|_| ())
+                .map_err(|err| SendError::Closed(err.0))
+        }
+    }
+
+    impl<T> CloneableSender<T> for TokioBroadcastSender<T> {
+        fn send(&self, t: T) -> Result<(), SendError<T>> {
+            TokioBroadcastSender::send(self, t)
+                .map(|_| ())
+                .map_err(|err| SendError::Closed(err.0))
+        }
+    }
+}
+
+//
+mod latest_impl {
+    use super::*;
+
+    use crate::{error::SendErrorWithoutFull, latest::LatestSender};
+
+    impl<T> LatestSender<T> for TokioWatchSender<T> {
+        fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>> {
+            TokioWatchSender::send(self, t).map_err(|err| SendErrorWithoutFull::Closed(err.0))
+        }
+
+        fn send_modify(&self, modify: &mut dyn FnMut(&mut T)) {
+            TokioWatchSender::send_modify(self, modify)
+        }
+    }
+}
+
+//
+mod broadcast_impl {
+    use super::*;
+
+    use crate::{broadcast::BroadcastSender, error::SendError};
+
+    impl<T> BroadcastSender<T> for TokioBroadcastSender<T> {
+        fn send(&self, t: T) -> Result<usize, SendError<T>> {
+            TokioBroadcastSender::send(self, t).map_err(|err| SendError::Closed(err.0))
+        }
+
+        fn overflow(&self) -> bool {
+            true
+        }
+
+        fn set_overflow(&mut self, overflow: bool) -> bool {
+            // tokio always lets slow receivers lag, so turning overflow off is the unsupported
+            // mode and reports `false`.
+            overflow
+        }
+    }
 }
 
 //
@@ -109,11 +295,57 @@ mod error_convert {
     }
 }
 
+//
+#[cfg(feature = "factory_tokio")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioFactory;
+
+#[cfg(feature = "factory_tokio")]
+mod factory_impl {
+    use super::*;
+
+    use channel_receiver::impl_tokio::TokioOneshotReceiverWrapper;
+
+    use crate::factory::{
+        BoxAsyncReceiver, BoxBoundedSender, BoxOneshotReceiver, BoxOneshotSender,
+        BoxUnboundedSender, ChannelFactory,
+    };
+
+    impl ChannelFactory for TokioFactory {
+        fn bounded<T>(&self, capacity: usize) -> (BoxBoundedSender<T>, BoxAsyncReceiver<T>)
+        where
+            T: Send + 'static,
+        {
+            assert!(capacity > 0, "capacity must be greater than zero");
+            let (tx, rx) = tokio::sync::mpsc::channel(capacity);
+            (Box::new(tx), Box::new(rx))
+        }
+
+        fn unbounded<T>(&self) -> (BoxUnboundedSender<T>, BoxAsyncReceiver<T>)
+        where
+            T: Send + 'static,
+        {
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            (Box::new(tx), Box::new(rx))
+        }
+
+        fn oneshot<T>(&self) -> Option<(BoxOneshotSender<T>, BoxOneshotReceiver<T>)>
+        where
+            T: Send + 'static,
+        {
+            let (tx, rx) = tokio::sync::oneshot::channel();
+            Some((Box::new(tx), Box::new(TokioOneshotReceiverWrapper(rx))))
+        }
+    }
+}
+
 #[cfg(test)]
 mod multi_producer_impl_tests {
     use crate::{
-        error::{SendError, SendErrorWithoutFull},
-        multi_producer::{BoundedSender, UnboundedSender},
+        error::{SendBatchError, SendError, SendErrorWithoutFull},
+        multi_producer::{
+            BlockingBoundedSender, BoundedSender, ReservableBoundedSender, UnboundedSender,
+        },
     };
 
     #[tokio::test]
@@ -150,6 +382,185 @@ mod multi_producer_impl_tests {
             assert_eq!(sender.send(3), Err(SendErrorWithoutFull::Closed(3)));
         }
     }
+
+    #[tokio::test]
+    async fn test_batch_with_channel() {
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+            let sender: Box<dyn BoundedSender<usize>> = Box::new(tx);
+            assert_eq!(sender.try_send_batch(vec![]), Ok(()));
+            assert_eq!(
+                sender.try_send_batch(vec![1, 2, 3, 4]),
+                Err(SendBatchError::Full(vec![3, 4]))
+            );
+            assert_eq!(rx.recv().await, Some(1));
+            let handle = tokio::spawn(async move {
+                let mut buf = vec![];
+                while buf.len() < 3 {
+                    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+                    buf.push(rx.recv().await.unwrap());
+                }
+                rx
+            });
+            assert_eq!(sender.send_all(vec![3, 4]).await, Ok(()));
+            let rx = handle.await.unwrap();
+            drop(rx);
+            assert_eq!(
+                sender.send_all(vec![5, 6]).await,
+                Err(SendBatchError::Closed(vec![5, 6]))
+            );
+            assert_eq!(
+                sender.try_send_batch(vec![5, 6]),
+                Err(SendBatchError::Closed(vec![5, 6]))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_with_unbounded_channel() {
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            let sender: Box<dyn UnboundedSender<usize>> = Box::new(tx);
+            assert_eq!(sender.send_all(vec![1, 2]), Ok(()));
+            assert_eq!(rx.recv().await, Some(1));
+            assert_eq!(rx.recv().await, Some(2));
+            drop(rx);
+            assert_eq!(
+                sender.send_all(vec![3, 4]),
+                Err(SendBatchError::Closed(vec![3, 4]))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reserve_with_channel() {
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+            let sender: Box<dyn ReservableBoundedSender<usize> + Send + Sync> = Box::new(tx);
+            let other = sender.clone();
+            let permit = sender.try_reserve().unwrap();
+            assert_eq!(sender.try_send(1), Err(SendError::Full(1)));
+            assert_eq!(other.try_send(1), Err(SendError::Full(1)));
+            assert_eq!(sender.try_reserve().err(), Some(SendError::Full(())));
+            assert!(tokio::time::timeout(
+                tokio::time::Duration::from_millis(200),
+                sender.reserve()
+            )
+            .await
+            .is_err());
+            permit.send(1);
+            assert_eq!(rx.recv().await, Some(1));
+
+            let permit = sender.reserve().await.unwrap();
+            assert_eq!(sender.try_send(2), Err(SendError::Full(2)));
+            drop(permit);
+            assert_eq!(sender.try_send(2), Ok(()));
+            assert_eq!(rx.recv().await, Some(2));
+
+            drop(rx);
+            assert_eq!(
+                sender.reserve().await.err(),
+                Some(SendErrorWithoutFull::Closed(()))
+            );
+            assert_eq!(sender.try_reserve().err(), Some(SendError::Closed(())));
+        }
+    }
+
+    #[cfg(feature = "timer_tokio")]
+    #[tokio::test]
+    async fn test_send_timeout_with_channel() {
+        use crate::{error::SendTimeoutError, timer::TokioTimer};
+
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+            let sender: Box<dyn BoundedSender<usize>> = Box::new(tx);
+            let timeout = tokio::time::Duration::from_millis(100);
+            assert_eq!(sender.send_timeout(1, timeout, &TokioTimer).await, Ok(()));
+            assert_eq!(
+                sender.send_timeout(2, timeout, &TokioTimer).await,
+                Err(SendTimeoutError::Timeout(Some(2)))
+            );
+            let deadline = std::time::Instant::now() + timeout;
+            assert_eq!(
+                sender.send_deadline(2, deadline, &TokioTimer).await,
+                Err(SendTimeoutError::Timeout(Some(2)))
+            );
+            assert_eq!(rx.recv().await, Some(1));
+            assert_eq!(sender.send_timeout(2, timeout, &TokioTimer).await, Ok(()));
+            assert_eq!(rx.recv().await, Some(2));
+            drop(rx);
+            assert_eq!(
+                sender.send_timeout(3, timeout, &TokioTimer).await,
+                Err(SendTimeoutError::Closed(3))
+            );
+        }
+    }
+
+    #[test]
+    fn test_blocking_with_channel() {
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+            let sender: Box<dyn BlockingBoundedSender<usize>> = Box::new(tx);
+            let sender = sender.clone();
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.try_send(2), Err(SendError::Full(2)));
+            let handle = std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                assert_eq!(rx.blocking_recv(), Some(1));
+                rx
+            });
+            assert_eq!(sender.send(2), Ok(()));
+            let mut rx = handle.join().unwrap();
+            assert_eq!(rx.blocking_recv(), Some(2));
+            drop(rx);
+            assert_eq!(sender.send(3), Err(SendErrorWithoutFull::Closed(3)));
+            assert_eq!(sender.try_send(3), Err(SendError::Closed(3)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod multi_producer_native_impl_tests {
+    use crate::{
+        error::{SendError, SendErrorWithoutFull},
+        multi_producer::{BoundedSender, NativeBoundedSender},
+    };
+
+    async fn send_all<S: NativeBoundedSender<usize>>(
+        sender: &S,
+        list: Vec<usize>,
+    ) -> Result<(), SendErrorWithoutFull<usize>> {
+        for v in list {
+            sender.send(v).await?;
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+            assert_eq!(send_all(&tx, vec![1, 2]).await, Ok(()));
+            assert_eq!(
+                NativeBoundedSender::try_send(&tx, 3),
+                Err(SendError::Full(3))
+            );
+            assert_eq!(rx.recv().await, Some(1));
+            assert_eq!(rx.recv().await, Some(2));
+            drop(rx);
+            assert_eq!(
+                send_all(&tx, vec![3]).await,
+                Err(SendErrorWithoutFull::Closed(3))
+            );
+        }
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+            let sender: Box<dyn BoundedSender<usize> + Send + Sync> = Box::new(tx);
+            assert_eq!(send_all(&sender, vec![1, 2]).await, Ok(()));
+            assert_eq!(rx.recv().await, Some(1));
+            assert_eq!(rx.recv().await, Some(2));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -176,6 +587,39 @@ mod one_shot_impl_tests {
             assert_eq!(rx.await, Ok(1));
         }
     }
+
+    #[tokio::test]
+    async fn test_closed_with_channel() {
+        {
+            let (tx, rx) = tokio::sync::oneshot::channel::<usize>();
+            let mut sender: Box<dyn BoxSender<usize> + Send> = Box::new(tx);
+            assert_eq!(sender.is_closed(), Some(false));
+            drop(rx);
+            assert_eq!(sender.is_closed(), Some(true));
+            sender.closed().unwrap().await;
+        }
+
+        // A handler gives up on its work once the caller goes away.
+        {
+            let (tx, rx) = tokio::sync::oneshot::channel::<usize>();
+            let mut sender: Box<dyn BoxSender<usize> + Send> = Box::new(tx);
+
+            let handle = tokio::spawn(async move {
+                let cancelled = tokio::select! {
+                    _ = tokio::time::sleep(tokio::time::Duration::from_secs(60)) => false,
+                    _ = sender.closed().unwrap() => true,
+                };
+                if !cancelled {
+                    assert_eq!(sender.send(1), Ok(()));
+                }
+                cancelled
+            });
+
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+            drop(rx);
+            assert!(handle.await.unwrap());
+        }
+    }
 }
 
 #[cfg(test)]
@@ -233,3 +677,237 @@ mod generic_impl_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod introspect_impl_tests {
+    use crate::multi_producer::{BoundedSender, UnboundedSender};
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+            let sender: Box<dyn BoundedSender<usize>> = Box::new(tx);
+            assert_eq!(sender.len(), Some(0));
+            assert_eq!(sender.is_empty(), Some(true));
+            assert_eq!(sender.capacity(), Some(2));
+            assert_eq!(sender.max_capacity(), Some(2));
+            assert_eq!(sender.send(1).await, Ok(()));
+            assert_eq!(sender.len(), Some(1));
+            assert_eq!(sender.capacity(), Some(1));
+            assert_eq!(sender.is_closed(), Some(false));
+            assert_eq!(rx.recv().await, Some(1));
+            assert_eq!(sender.len(), Some(0));
+            drop(rx);
+            assert_eq!(sender.is_closed(), Some(true));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_unbounded_channel() {
+        {
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            let sender: Box<dyn UnboundedSender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.len(), None);
+            assert_eq!(sender.capacity(), None);
+            assert_eq!(sender.max_capacity(), None);
+            assert_eq!(sender.is_closed(), Some(false));
+            drop(rx);
+            assert_eq!(sender.is_closed(), Some(true));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_counts_with_channel() {
+        {
+            let (tx, _rx) = tokio::sync::mpsc::channel::<usize>(1);
+            let sender: Box<dyn BoundedSender<usize> + Send + Sync> = Box::new(tx);
+            assert_eq!(sender.sender_count(), Some(1));
+            assert_eq!(sender.receiver_count(), None);
+
+            let cloned = sender.clone();
+            assert_eq!(sender.sender_count(), Some(2));
+            assert_eq!(sender.same_channel(&cloned), Some(true));
+            drop(cloned);
+            assert_eq!(sender.sender_count(), Some(1));
+
+            let (tx, _rx) = tokio::sync::mpsc::channel::<usize>(1);
+            let other: Box<dyn BoundedSender<usize> + Send + Sync> = Box::new(tx);
+            assert_eq!(sender.same_channel(&other), Some(false));
+
+            let (tx, _rx) = tokio::sync::mpsc::unbounded_channel::<usize>();
+            let other: Box<dyn UnboundedSender<usize> + Send + Sync> = Box::new(tx);
+            assert_eq!(sender.same_channel(&other), Some(false));
+            assert_eq!(other.same_channel(&other.clone()), Some(true));
+        }
+    }
+}
+
+#[cfg(test)]
+mod closed_impl_tests {
+    use crate::{
+        multi_producer::{BoundedSender, UnboundedSender},
+        one_shot::Sender,
+    };
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, rx) = tokio::sync::mpsc::channel::<usize>(1);
+            let sender: Box<dyn BoundedSender<usize> + Send + Sync> = Box::new(tx);
+            let closed = sender.closed().unwrap();
+            let handle = tokio::spawn(async move {
+                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+                drop(rx);
+            });
+            closed.await;
+            handle.await.unwrap();
+            assert!(sender.try_send(1).is_err());
+        }
+        {
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<usize>();
+            let sender: Box<dyn UnboundedSender<usize> + Send + Sync> = Box::new(tx);
+            assert!(tokio::time::timeout(
+                tokio::time::Duration::from_millis(100),
+                sender.closed().unwrap()
+            )
+            .await
+            .is_err());
+            drop(rx);
+            sender.closed().unwrap().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_oneshot_channel() {
+        {
+            let (mut tx, rx) = tokio::sync::oneshot::channel::<usize>();
+            let handle = tokio::spawn(async move {
+                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+                drop(rx);
+            });
+            Sender::closed(&mut tx).unwrap().await;
+            handle.await.unwrap();
+            assert!(Sender::send(tx, 1).is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod weak_impl_tests {
+    use crate::multi_producer::{BoundedSender, UnboundedSender};
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+            let sender: Box<dyn BoundedSender<usize> + Send + Sync> = Box::new(tx);
+            let weak = sender.downgrade().unwrap();
+
+            let upgraded = weak.upgrade().unwrap();
+            assert_eq!(upgraded.send(1).await, Ok(()));
+            assert_eq!(rx.recv().await, Some(1));
+            drop(upgraded);
+
+            drop(sender);
+            assert_eq!(rx.recv().await, None);
+            assert!(weak.upgrade().is_none());
+        }
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            let sender: Box<dyn UnboundedSender<usize> + Send + Sync> = Box::new(tx);
+            let weak = sender.downgrade().unwrap();
+
+            assert_eq!(weak.upgrade().unwrap().send(1), Ok(()));
+            assert_eq!(rx.recv().await, Some(1));
+
+            drop(sender);
+            assert_eq!(rx.recv().await, None);
+            assert!(weak.clone().upgrade().is_none());
+        }
+    }
+}
+
+#[cfg(test)]
+mod broadcast_impl_tests {
+    use crate::{broadcast::BroadcastSender, error::SendError, generic::Sender};
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, mut rx1) = tokio::sync::broadcast::channel(2);
+            let mut rx2 = tx.subscribe();
+            let sender: Box<dyn BroadcastSender<usize> + Send + Sync> = Box::new(tx);
+            assert_eq!(sender.receiver_count(), Some(2));
+            assert_eq!(sender.send(1), Ok(2));
+            assert_eq!(sender.len(), Some(1));
+            assert_eq!(rx1.recv().await, Ok(1));
+            assert_eq!(rx2.recv().await, Ok(1));
+            assert_eq!(sender.is_empty(), Some(true));
+            assert_eq!(sender.same_channel(&sender.clone()), Some(true));
+
+            drop(rx1);
+            assert_eq!(sender.send(2), Ok(1));
+            drop(rx2);
+            assert_eq!(sender.send(3), Err(SendError::Closed(3)));
+
+            let mut sender = sender;
+            assert!(sender.overflow());
+            assert!(!sender.set_overflow(false));
+            assert!(sender.overflow());
+            assert!(sender.set_overflow(true));
+        }
+        {
+            let (tx, mut rx) = tokio::sync::broadcast::channel(1);
+            let sender: Box<dyn Sender<usize>> = Box::new(tx);
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(rx.recv().await, Ok(1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod latest_impl_tests {
+    use crate::{error::SendErrorWithoutFull, latest::LatestSender};
+
+    #[tokio::test]
+    async fn test_with_channel() {
+        {
+            let (tx, mut rx) = tokio::sync::watch::channel(0);
+            let sender: Box<dyn LatestSender<usize> + Send + Sync> = Box::new(tx);
+            assert_eq!(sender.receiver_count(), Some(1));
+
+            assert_eq!(sender.send(1), Ok(()));
+            assert_eq!(sender.send(2), Ok(()));
+            assert!(rx.changed().await.is_ok());
+            assert_eq!(*rx.borrow_and_update(), 2);
+
+            sender.send_modify(&mut |v| *v += 1);
+            assert!(rx.changed().await.is_ok());
+            assert_eq!(*rx.borrow_and_update(), 3);
+
+            let cloned = sender.clone();
+            drop(rx);
+            assert_eq!(sender.is_closed(), Some(true));
+            assert_eq!(cloned.send(4), Err(SendErrorWithoutFull::Closed(4)));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "factory_tokio"))]
+mod factory_impl_tests {
+    use super::*;
+
+    use crate::factory::factory_tests;
+
+    #[tokio::test]
+    async fn test_with_factory() {
+        factory_tests::test_factory(TokioFactory).await;
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be greater than zero")]
+    fn test_with_zero_capacity() {
+        factory_tests::test_zero_capacity(TokioFactory);
+    }
+}
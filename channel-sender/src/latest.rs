@@ -0,0 +1,15 @@
+use dyn_clone::{clone_trait_object, DynClone};
+
+use crate::{error::SendErrorWithoutFull, introspect::Introspect};
+
+//
+// Only the most recent value is kept, so receivers that were busy only see the last one.
+// How many receivers are subscribed is reported by `Introspect::receiver_count`.
+pub trait LatestSender<T>: DynClone + Introspect {
+    // Fails, keeping the old value, if every receiver is gone.
+    fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>;
+
+    // Edits the value in place, receivers are told it changed even if `modify` left it as is.
+    fn send_modify(&self, modify: &mut dyn FnMut(&mut T));
+}
+clone_trait_object!(<T> LatestSender<T>);
@@ -7,13 +7,43 @@ pub use one_shot as oneshot;
 
 pub mod generic;
 
+pub mod broadcast;
+
+pub mod latest;
+
+pub use channel_receiver::introspect;
+pub use introspect::Introspect;
+
+pub use channel_receiver::timer;
+pub use timer::Timer;
+
+pub mod sink;
+pub use sink::SenderSink;
+
 pub mod error;
-pub use error::{SendError, SendErrorWithoutFull};
+pub use error::{SendBatchError, SendError, SendErrorWithoutFull, SendTimeoutError};
 
 //
+#[cfg(feature = "impl_async_broadcast")]
+pub mod impl_async_broadcast;
 #[cfg(feature = "impl_async_channel")]
 pub mod impl_async_channel;
+#[cfg(feature = "impl_crossbeam")]
+pub mod impl_crossbeam;
+#[cfg(feature = "impl_flume")]
+pub mod impl_flume;
+#[cfg(feature = "impl_futures_channel")]
+pub mod impl_futures_channel;
+#[cfg(feature = "impl_kanal")]
+pub mod impl_kanal;
 #[cfg(feature = "impl_tokio")]
 pub mod impl_tokio;
 
 pub mod impl_std;
+
+//
+#[cfg(feature = "bridge")]
+pub mod bridge;
+
+#[cfg(feature = "factory")]
+pub mod factory;
@@ -1,19 +1,424 @@
+use core::{future::Future, pin::Pin, time::Duration};
+use std::time::Instant;
+
 use dyn_clone::{clone_trait_object, DynClone};
 
-use crate::error::{SendError, SendErrorWithoutFull};
+use crate::{
+    error::{SendBatchError, SendError, SendErrorWithoutFull, SendTimeoutError},
+    introspect::Introspect,
+    timer::{timeout_at, Timer},
+};
 
 //
 #[async_trait::async_trait]
-pub trait BoundedSender<T>: DynClone {
+pub trait BoundedSender<T>: DynClone + Introspect + Send + Sync {
+    // The bound on `T` lets the blanket impl over `NativeBoundedSender` move `t` into its future.
     async fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>
     where
-        T: Send;
+        T: Send + 'async_trait;
 
     fn try_send(&self, t: T) -> Result<(), SendError<T>>;
+
+    fn closed(&self) -> Option<Closed<'_>>
+    where
+        T: Send,
+    {
+        None
+    }
+
+    fn downgrade(&self) -> Option<BoxWeakSender<dyn BoundedSender<T> + Send + Sync>>
+    where
+        T: Send + 'static,
+    {
+        None
+    }
+
+    // Not an `async fn`: the future owns the values, so `T` has to outlive it.
+    fn send_all<'a>(
+        &'a self,
+        ts: Vec<T>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), SendBatchError<T>>> + Send + 'a>>
+    where
+        T: Send + 'a,
+    {
+        Box::pin(async move {
+            let mut ts = ts.into_iter();
+            for t in ts.by_ref() {
+                if let Err(err) = BoundedSender::send(self, t).await {
+                    return Err(SendBatchError::from(err).with_remaining(ts));
+                }
+            }
+            Ok(())
+        })
+    }
+
+    fn try_send_batch(&self, ts: Vec<T>) -> Result<(), SendBatchError<T>> {
+        let mut ts = ts.into_iter();
+        for t in ts.by_ref() {
+            if let Err(err) = BoundedSender::try_send(self, t) {
+                return Err(SendBatchError::from(err).with_remaining(ts));
+            }
+        }
+        Ok(())
+    }
+
+    fn send_timeout<'a>(
+        &'a self,
+        t: T,
+        timeout: Duration,
+        timer: &'a dyn Timer,
+    ) -> Pin<Box<dyn Future<Output = Result<(), SendTimeoutError<T>>> + Send + 'a>>
+    where
+        T: Send + 'a,
+    {
+        match timer.now().checked_add(timeout) {
+            Some(deadline) => BoundedSender::send_deadline(self, t, deadline, timer),
+            None => Box::pin(async move { BoundedSender::send(self, t).await.map_err(Into::into) }),
+        }
+    }
+
+    fn send_deadline<'a>(
+        &'a self,
+        t: T,
+        deadline: Instant,
+        timer: &'a dyn Timer,
+    ) -> Pin<Box<dyn Future<Output = Result<(), SendTimeoutError<T>>> + Send + 'a>>
+    where
+        T: Send + 'a,
+    {
+        Box::pin(async move {
+            match timeout_at(BoundedSender::send(self, t), deadline, timer).await {
+                Some(result) => result.map_err(Into::into),
+                None => Err(SendTimeoutError::Timeout(None)),
+            }
+        })
+    }
 }
 clone_trait_object!(<T> BoundedSender<T>);
 
-pub trait UnboundedSender<T>: DynClone {
+// Resolves once every receiver is gone.
+pub type Closed<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+//
+// Does not count towards the senders keeping the channel open, so the receiver sees it closed
+// once every strong sender is dropped.
+pub trait WeakSender<S: ?Sized>: DynClone {
+    fn upgrade(&self) -> Option<Box<S>>;
+}
+clone_trait_object!(<S> WeakSender<S> where S: ?Sized);
+
+pub type BoxWeakSender<S> = Box<dyn WeakSender<S> + Send + Sync>;
+
+//
+#[async_trait::async_trait]
+pub trait ReservableBoundedSender<T>: BoundedSender<T> {
+    async fn reserve(&self) -> Result<BoxPermit<'_, T>, SendErrorWithoutFull<()>>;
+
+    fn try_reserve(&self) -> Result<BoxPermit<'_, T>, SendError<()>>;
+}
+clone_trait_object!(<T> ReservableBoundedSender<T>);
+
+// Sending through a permit cannot fail for lack of capacity, the slot was taken by `reserve`.
+pub trait Permit<T> {
+    fn send(self: Box<Self>, t: T);
+}
+
+pub type BoxPermit<'a, T> = Box<dyn Permit<T> + Send + 'a>;
+
+//
+pub trait BlockingBoundedSender<T>: DynClone + Introspect {
+    fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>;
+
+    fn try_send(&self, t: T) -> Result<(), SendError<T>>;
+
+    fn send_all(&self, ts: Vec<T>) -> Result<(), SendBatchError<T>> {
+        let mut ts = ts.into_iter();
+        for t in ts.by_ref() {
+            if let Err(err) = BlockingBoundedSender::send(self, t) {
+                return Err(SendBatchError::from(err).with_remaining(ts));
+            }
+        }
+        Ok(())
+    }
+
+    fn try_send_batch(&self, ts: Vec<T>) -> Result<(), SendBatchError<T>> {
+        let mut ts = ts.into_iter();
+        for t in ts.by_ref() {
+            if let Err(err) = BlockingBoundedSender::try_send(self, t) {
+                return Err(SendBatchError::from(err).with_remaining(ts));
+            }
+        }
+        Ok(())
+    }
+}
+clone_trait_object!(<T> BlockingBoundedSender<T>);
+
+pub trait UnboundedSender<T>: DynClone + Introspect {
     fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>;
+
+    // `None` if the backend can't tell when the receivers are gone.
+    fn closed(&self) -> Option<Closed<'_>>
+    where
+        T: Send,
+    {
+        None
+    }
+
+    // `None` if the backend has no weak handles.
+    fn downgrade(&self) -> Option<BoxWeakSender<dyn UnboundedSender<T> + Send + Sync>>
+    where
+        T: Send + 'static,
+    {
+        None
+    }
+
+    fn send_all(&self, ts: Vec<T>) -> Result<(), SendBatchError<T>> {
+        let mut ts = ts.into_iter();
+        for t in ts.by_ref() {
+            if let Err(err) = UnboundedSender::send(self, t) {
+                return Err(SendBatchError::from(err).with_remaining(ts));
+            }
+        }
+        Ok(())
+    }
 }
 clone_trait_object!(<T> UnboundedSender<T>);
+
+//
+pub trait NativeBoundedSender<T>: Clone + Introspect {
+    fn send(&self, t: T) -> impl Future<Output = Result<(), SendErrorWithoutFull<T>>> + Send
+    where
+        T: Send;
+
+    fn try_send(&self, t: T) -> Result<(), SendError<T>>;
+
+    // `None` if the backend can't tell when the receivers are gone.
+    fn closed(&self) -> Option<Closed<'_>>
+    where
+        T: Send,
+    {
+        None
+    }
+
+    // `None` if the backend has no weak handles.
+    fn downgrade(&self) -> Option<BoxWeakSender<dyn BoundedSender<T> + Send + Sync>>
+    where
+        T: Send + 'static,
+    {
+        None
+    }
+
+    // Waits for capacity for each value in turn, so it only fails once the channel is closed.
+    fn send_all<'a>(
+        &'a self,
+        ts: Vec<T>,
+    ) -> impl Future<Output = Result<(), SendBatchError<T>>> + Send + 'a
+    where
+        T: Send + 'a,
+        Self: Sync,
+    {
+        async move {
+            let mut ts = ts.into_iter();
+            for t in ts.by_ref() {
+                if let Err(err) = NativeBoundedSender::send(self, t).await {
+                    return Err(SendBatchError::from(err).with_remaining(ts));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    // Sends as many values as the current capacity allows, handing back the rest.
+    fn try_send_batch(&self, ts: Vec<T>) -> Result<(), SendBatchError<T>> {
+        let mut ts = ts.into_iter();
+        for t in ts.by_ref() {
+            if let Err(err) = NativeBoundedSender::try_send(self, t) {
+                return Err(SendBatchError::from(err).with_remaining(ts));
+            }
+        }
+        Ok(())
+    }
+
+    fn send_timeout<'a>(
+        &'a self,
+        t: T,
+        timeout: Duration,
+        timer: &'a dyn Timer,
+    ) -> impl Future<Output = Result<(), SendTimeoutError<T>>> + Send + 'a
+    where
+        T: Send + 'a,
+        Self: Sync,
+    {
+        async move {
+            match timer.now().checked_add(timeout) {
+                Some(deadline) => {
+                    NativeBoundedSender::send_deadline(self, t, deadline, timer).await
+                }
+                None => NativeBoundedSender::send(self, t).await.map_err(Into::into),
+            }
+        }
+    }
+
+    // Races the backend's own send against the deadline. Most send futures drop the value when
+    // they are cancelled, so `Timeout` carries `None`; backends that can wait for room without
+    // giving the value up override this and hand it back.
+    fn send_deadline<'a>(
+        &'a self,
+        t: T,
+        deadline: Instant,
+        timer: &'a dyn Timer,
+    ) -> impl Future<Output = Result<(), SendTimeoutError<T>>> + Send + 'a
+    where
+        T: Send + 'a,
+        Self: Sync,
+    {
+        async move {
+            match timeout_at(NativeBoundedSender::send(self, t), deadline, timer).await {
+                Some(result) => result.map_err(Into::into),
+                None => Err(SendTimeoutError::Timeout(None)),
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, S> BoundedSender<T> for S
+where
+    S: NativeBoundedSender<T> + Send + Sync,
+{
+    async fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>
+    where
+        T: Send + 'async_trait,
+    {
+        NativeBoundedSender::send(self, t).await
+    }
+
+    fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+        NativeBoundedSender::try_send(self, t)
+    }
+
+    fn closed(&self) -> Option<Closed<'_>>
+    where
+        T: Send,
+    {
+        NativeBoundedSender::closed(self)
+    }
+
+    fn downgrade(&self) -> Option<BoxWeakSender<dyn BoundedSender<T> + Send + Sync>>
+    where
+        T: Send + 'static,
+    {
+        NativeBoundedSender::downgrade(self)
+    }
+
+    fn send_all<'a>(
+        &'a self,
+        ts: Vec<T>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), SendBatchError<T>>> + Send + 'a>>
+    where
+        T: Send + 'a,
+    {
+        Box::pin(NativeBoundedSender::send_all(self, ts))
+    }
+
+    fn try_send_batch(&self, ts: Vec<T>) -> Result<(), SendBatchError<T>> {
+        NativeBoundedSender::try_send_batch(self, ts)
+    }
+
+    fn send_timeout<'a>(
+        &'a self,
+        t: T,
+        timeout: Duration,
+        timer: &'a dyn Timer,
+    ) -> Pin<Box<dyn Future<Output = Result<(), SendTimeoutError<T>>> + Send + 'a>>
+    where
+        T: Send + 'a,
+    {
+        Box::pin(NativeBoundedSender::send_timeout(self, t, timeout, timer))
+    }
+
+    fn send_deadline<'a>(
+        &'a self,
+        t: T,
+        deadline: Instant,
+        timer: &'a dyn Timer,
+    ) -> Pin<Box<dyn Future<Output = Result<(), SendTimeoutError<T>>> + Send + 'a>>
+    where
+        T: Send + 'a,
+    {
+        Box::pin(NativeBoundedSender::send_deadline(self, t, deadline, timer))
+    }
+}
+
+// Lets a boxed sender go wherever a `NativeBoundedSender` is expected, e.g. into `SenderSink`.
+impl<T, S> NativeBoundedSender<T> for Box<S>
+where
+    S: BoundedSender<T> + ?Sized,
+    Box<S>: Clone,
+{
+    async fn send(&self, t: T) -> Result<(), SendErrorWithoutFull<T>>
+    where
+        T: Send,
+    {
+        BoundedSender::send(&**self, t).await
+    }
+
+    fn try_send(&self, t: T) -> Result<(), SendError<T>> {
+        BoundedSender::try_send(&**self, t)
+    }
+
+    fn closed(&self) -> Option<Closed<'_>>
+    where
+        T: Send,
+    {
+        BoundedSender::closed(&**self)
+    }
+
+    fn downgrade(&self) -> Option<BoxWeakSender<dyn BoundedSender<T> + Send + Sync>>
+    where
+        T: Send + 'static,
+    {
+        BoundedSender::downgrade(&**self)
+    }
+
+    fn send_all<'a>(
+        &'a self,
+        ts: Vec<T>,
+    ) -> impl Future<Output = Result<(), SendBatchError<T>>> + Send + 'a
+    where
+        T: Send + 'a,
+        Self: Sync,
+    {
+        BoundedSender::send_all(&**self, ts)
+    }
+
+    fn try_send_batch(&self, ts: Vec<T>) -> Result<(), SendBatchError<T>> {
+        BoundedSender::try_send_batch(&**self, ts)
+    }
+
+    fn send_timeout<'a>(
+        &'a self,
+        t: T,
+        timeout: Duration,
+        timer: &'a dyn Timer,
+    ) -> impl Future<Output = Result<(), SendTimeoutError<T>>> + Send + 'a
+    where
+        T: Send + 'a,
+        Self: Sync,
+    {
+        BoundedSender::send_timeout(&**self, t, timeout, timer)
+    }
+
+    fn send_deadline<'a>(
+        &'a self,
+        t: T,
+        deadline: Instant,
+        timer: &'a dyn Timer,
+    ) -> impl Future<Output = Result<(), SendTimeoutError<T>>> + Send + 'a
+    where
+        T: Send + 'a,
+        Self: Sync,
+    {
+        BoundedSender::send_deadline(&**self, t, deadline, timer)
+    }
+}
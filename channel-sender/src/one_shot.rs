@@ -1,10 +1,52 @@
-use crate::error::SendErrorWithoutFull;
+use core::{
+    future::poll_fn,
+    task::{Context, Poll},
+};
+
+use crate::{error::SendErrorWithoutFull, multi_producer::Closed};
 
 //
 pub trait Sender<T> {
     fn send(self, t: T) -> Result<(), SendErrorWithoutFull<T>>;
+
+    // `None` if the backend can't tell whether the receiver is gone.
+    fn is_closed(&self) -> Option<bool> {
+        None
+    }
+
+    // Never resolves if the backend can't tell, see `is_closed`.
+    fn poll_closed(&mut self, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+
+    // Resolves once the receiver is gone, `None` if the backend can't tell.
+    fn closed(&mut self) -> Option<Closed<'_>>
+    where
+        T: Send,
+        Self: Send,
+    {
+        self.is_closed()?;
+        Some(Box::pin(poll_fn(move |cx| self.poll_closed(cx))))
+    }
 }
 
 pub trait BoxSender<T> {
     fn send(self: Box<Self>, t: T) -> Result<(), SendErrorWithoutFull<T>>;
+
+    fn is_closed(&self) -> Option<bool> {
+        None
+    }
+
+    fn poll_closed(&mut self, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+
+    fn closed(&mut self) -> Option<Closed<'_>>
+    where
+        T: Send,
+        Self: Send,
+    {
+        self.is_closed()?;
+        Some(Box::pin(poll_fn(move |cx| self.poll_closed(cx))))
+    }
 }
@@ -0,0 +1,182 @@
+use core::{
+    fmt,
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_sink::Sink;
+
+use crate::{error::SendErrorWithoutFull, multi_producer::NativeBoundedSender};
+
+//
+type SendFuture<S, T> =
+    Pin<Box<dyn Future<Output = (Result<(), SendErrorWithoutFull<T>>, S)> + Send>>;
+
+enum State<S, T> {
+    Idle(S),
+    Sending(SendFuture<S, T>),
+    Closed,
+}
+
+//
+pub struct SenderSink<S, T> {
+    state: State<S, T>,
+}
+
+impl<S, T> fmt::Debug for SenderSink<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SenderSink")
+            .field("is_closed", &self.is_closed())
+            .finish()
+    }
+}
+
+impl<S, T> Unpin for SenderSink<S, T> {}
+
+impl<S, T> SenderSink<S, T>
+where
+    S: NativeBoundedSender<T> + Send + Sync + 'static,
+    T: Send + 'static,
+{
+    pub fn new(sender: S) -> Self {
+        Self {
+            state: State::Idle(sender),
+        }
+    }
+}
+
+impl<S, T> SenderSink<S, T> {
+    pub fn is_closed(&self) -> bool {
+        matches!(self.state, State::Closed)
+    }
+
+    fn poll_sending(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), SendErrorWithoutFull<T>>> {
+        match mem::replace(&mut self.state, State::Closed) {
+            State::Sending(mut fut) => match fut.as_mut().poll(cx) {
+                Poll::Ready((ret, sender)) => {
+                    self.state = State::Idle(sender);
+                    Poll::Ready(ret)
+                }
+                Poll::Pending => {
+                    self.state = State::Sending(fut);
+                    Poll::Pending
+                }
+            },
+            state => {
+                self.state = state;
+                Poll::Ready(Ok(()))
+            }
+        }
+    }
+}
+
+impl<S, T> Sink<T> for SenderSink<S, T>
+where
+    S: NativeBoundedSender<T> + Send + Sync + 'static,
+    T: Send + 'static,
+{
+    type Error = SendErrorWithoutFull<T>;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().poll_sending(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+
+        match mem::replace(&mut this.state, State::Closed) {
+            State::Idle(sender) => {
+                this.state = State::Sending(Box::pin(async move {
+                    let ret = NativeBoundedSender::send(&sender, item).await;
+                    (ret, sender)
+                }));
+                Ok(())
+            }
+            // `poll_ready` was skipped and the previous value is still in flight, so there is no
+            // room for this one yet.
+            State::Sending(fut) => {
+                this.state = State::Sending(fut);
+                Err(SendErrorWithoutFull::UnreachableFull(item))
+            }
+            State::Closed => Err(SendErrorWithoutFull::Closed(item)),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().poll_sending(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+
+        match this.poll_sending(cx) {
+            Poll::Ready(ret) => {
+                // Drop the sender, the receiver observes close once all senders are gone.
+                this.state = State::Closed;
+                Poll::Ready(ret)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "impl_tokio"))]
+mod tests {
+    use futures_util::{stream, SinkExt as _, StreamExt as _};
+
+    use super::*;
+    use crate::multi_producer::BoundedSender;
+
+    #[tokio::test]
+    async fn test_with_tokio_channel() {
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+            let sender: Box<dyn BoundedSender<usize>> = Box::new(tx);
+            let mut sink = SenderSink::new(sender);
+            assert_eq!(sink.send(1).await, Ok(()));
+            assert!(
+                tokio::time::timeout(tokio::time::Duration::from_millis(200), sink.send(2))
+                    .await
+                    .is_err()
+            );
+            assert_eq!(rx.recv().await, Some(1));
+            assert_eq!(sink.flush().await, Ok(()));
+            assert_eq!(rx.recv().await, Some(2));
+            assert_eq!(sink.close().await, Ok(()));
+            assert!(sink.is_closed());
+            assert_eq!(rx.recv().await, None);
+            assert_eq!(sink.send(3).await, Err(SendErrorWithoutFull::Closed(3)));
+        }
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+            let sink = SenderSink::new(tx);
+            let handle =
+                tokio::spawn(async move { stream::iter(1..=3).map(Ok).forward(sink).await });
+            for i in 1..=3 {
+                tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+                assert_eq!(rx.recv().await, Some(i));
+            }
+            assert_eq!(handle.await.unwrap(), Ok(()));
+            assert_eq!(rx.recv().await, None);
+        }
+        {
+            let (tx, mut rx) = tokio::sync::mpsc::channel(1);
+            let mut sink = SenderSink::new(tx);
+            assert_eq!(Pin::new(&mut sink).start_send(1), Ok(()));
+            assert_eq!(
+                Pin::new(&mut sink).start_send(2),
+                Err(SendErrorWithoutFull::UnreachableFull(2))
+            );
+            assert_eq!(sink.flush().await, Ok(()));
+            assert_eq!(rx.recv().await, Some(1));
+        }
+        {
+            let (tx, rx) = tokio::sync::mpsc::channel(1);
+            let mut sink = SenderSink::new(tx);
+            drop(rx);
+            assert_eq!(sink.send(1).await, Err(SendErrorWithoutFull::Closed(1)));
+        }
+    }
+}